@@ -5,6 +5,8 @@
 use hound::{WavSpec, WavWriter};
 use std::fs;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::error::AppError;
 
@@ -26,9 +28,20 @@ pub fn get_temp_dir() -> PathBuf {
         .join("temp")
 }
 
-/// Retourne le chemin du fichier WAV temporaire
+/// Compteur garantissant des noms distincts pour deux enregistrements de la même milliseconde
+static RECORDING_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Retourne un nouveau chemin de fichier WAV temporaire, unique par enregistrement
+///
+/// Format: recording-<timestamp ms>-<compteur>.wav, pour qu'un nouvel
+/// enregistrement n'écrase jamais un fichier en attente de transcription.
 pub fn get_wav_path() -> PathBuf {
-    get_temp_dir().join("recording.wav")
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    let sequence = RECORDING_COUNTER.fetch_add(1, Ordering::Relaxed);
+    get_temp_dir().join(format!("recording-{}-{}.wav", timestamp, sequence))
 }
 
 /// Sauvegarde les samples audio dans un fichier WAV
@@ -72,7 +85,6 @@ pub fn save_wav(samples: &[f32], sample_rate: u32) -> Result<PathBuf, AppError>
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::time::{SystemTime, UNIX_EPOCH};
 
     /// Sous-dossier dédié aux tests buffer (isolé du cleanup_temp_files)
     fn get_test_dir() -> PathBuf {
@@ -136,7 +148,14 @@ mod tests {
     #[test]
     fn test_get_wav_path_returns_valid_path() {
         let wav_path = get_wav_path();
-        assert!(wav_path.to_string_lossy().ends_with("recording.wav"));
+        let name = wav_path.file_name().unwrap().to_string_lossy().to_string();
+        assert!(name.starts_with("recording-"));
+        assert!(name.ends_with(".wav"));
+    }
+
+    #[test]
+    fn test_get_wav_path_is_unique() {
+        assert_ne!(get_wav_path(), get_wav_path());
     }
 
     #[test]
@@ -208,7 +227,7 @@ mod tests {
 
         let path = result.unwrap();
         assert!(path.exists());
-        assert!(path.to_string_lossy().ends_with("recording.wav"));
+        assert!(path.to_string_lossy().ends_with(".wav"));
 
         // Cleanup
         let _ = fs::remove_file(path);
@@ -0,0 +1,230 @@
+//! Audio memory module - recordings that never touch the disk
+//!
+//! Privacy mode ([privacy] in_memory): samples returned by
+//! `RecordingHandle::stop` are kept here under a `memory://` ID until
+//! `start_transcription` hands them to the engine, at full f32 precision.
+//! Code that needs a file (window-by-window reading of long recordings) gets
+//! an unlinked memfd instead: RAM-backed, invisible in the data directory,
+//! gone when closed.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::error::AppError;
+
+/// Préfixe des identifiants d'enregistrements en mémoire
+pub const MEMORY_PREFIX: &str = "memory://";
+
+/// Échantillons d'un enregistrement, partagés sans copie avec la tâche
+pub type Samples = Arc<[f32]>;
+
+/// Compteur garantissant des identifiants distincts dans la même milliseconde
+static MEMORY_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Indique si `audio_path` désigne un enregistrement en mémoire.
+pub fn is_memory_id(audio_path: &str) -> bool {
+    audio_path.starts_with(MEMORY_PREFIX)
+}
+
+/// Enregistrements en mémoire en attente de transcription.
+///
+/// Une fois remis à une tâche, les échantillons sont libérés avec elle (fin,
+/// annulation, fin de rétention); ceux jamais transcrits le sont par
+/// `discard_recording` ou à l'arrêt de l'application.
+#[derive(Debug, Default)]
+pub struct MemoryRecordings {
+    pending: Mutex<HashMap<String, Samples>>,
+}
+
+impl MemoryRecordings {
+    /// Garde les échantillons et retourne leur identifiant (`memory://recording-<ms>-<n>`).
+    pub fn insert(&self, samples: Vec<f32>) -> String {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or(0);
+        let sequence = MEMORY_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let id = format!("{}recording-{}-{}", MEMORY_PREFIX, timestamp, sequence);
+
+        self.pending
+            .lock()
+            .expect("Memory recordings lock poisoned")
+            .insert(id.clone(), samples.into());
+        id
+    }
+
+    /// Retire un enregistrement pour le confier à une tâche.
+    pub fn take(&self, id: &str) -> Option<Samples> {
+        self.pending
+            .lock()
+            .expect("Memory recordings lock poisoned")
+            .remove(id)
+    }
+
+    /// Oublie tous les enregistrements en attente (arrêt de l'application).
+    pub fn clear(&self) {
+        self.pending
+            .lock()
+            .expect("Memory recordings lock poisoned")
+            .clear();
+    }
+}
+
+/// Rééchantillonne par interpolation linéaire (suffisant pour la parole).
+///
+/// Whisper attend du 16kHz: un micro qui ne le propose pas enregistre à sa
+/// fréquence par défaut (souvent 44.1 ou 48kHz).
+pub fn resample(samples: Vec<f32>, from_rate: u32, to_rate: u32) -> Vec<f32> {
+    if from_rate == to_rate || samples.is_empty() || from_rate == 0 || to_rate == 0 {
+        return samples;
+    }
+    let ratio = from_rate as f64 / to_rate as f64;
+    let len = (samples.len() as u64 * to_rate as u64 / from_rate as u64) as usize;
+    (0..len)
+        .map(|i| {
+            let position = i as f64 * ratio;
+            let index = position as usize;
+            let fraction = (position - index as f64) as f32;
+            let current = samples[index];
+            let next = samples.get(index + 1).copied().unwrap_or(current);
+            current + (next - current) * fraction
+        })
+        .collect()
+}
+
+/// Fichier WAV anonyme en mémoire (memfd), lisible via `path()` tant qu'il vit.
+#[derive(Debug)]
+pub struct MemoryWav {
+    /// Garde le descripteur ouvert: le fichier disparaît à sa fermeture
+    _file: File,
+    path: PathBuf,
+}
+
+impl MemoryWav {
+    /// Chemin `/proc/self/fd/<n>` du fichier, pour les lecteurs WAV.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Écrit les échantillons dans un memfd, en WAV float 32 bits (sans perte).
+///
+/// # Errors
+/// - `IoError` si le memfd ne peut être créé ou écrit
+#[cfg(target_os = "linux")]
+pub fn memfd_wav(samples: &[f32], sample_rate: u32) -> Result<MemoryWav, AppError> {
+    use std::io::BufWriter;
+    use std::os::fd::{AsRawFd, FromRawFd};
+
+    // SAFETY: nom C terminé par NUL; le descripteur retourné est valide s'il est ≥ 0
+    let fd = unsafe { libc::memfd_create(c"vocal-note-taker-audio".as_ptr(), libc::MFD_CLOEXEC) };
+    if fd < 0 {
+        return Err(AppError::IoError(format!(
+            "Cannot create in-memory audio file: {}",
+            std::io::Error::last_os_error()
+        )));
+    }
+    // SAFETY: fd vient d'être créé et n'appartient qu'à ce File
+    let file = unsafe { File::from_raw_fd(fd) };
+
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate,
+        bits_per_sample: 32,
+        sample_format: hound::SampleFormat::Float,
+    };
+    let mut writer = hound::WavWriter::new(BufWriter::new(&file), spec)
+        .map_err(|e| AppError::IoError(format!("Cannot create in-memory WAV: {}", e)))?;
+    for &sample in samples {
+        writer
+            .write_sample(sample)
+            .map_err(|e| AppError::IoError(format!("Cannot write in-memory WAV: {}", e)))?;
+    }
+    writer
+        .finalize()
+        .map_err(|e| AppError::IoError(format!("Cannot finalize in-memory WAV: {}", e)))?;
+
+    let path = PathBuf::from(format!("/proc/self/fd/{}", file.as_raw_fd()));
+    Ok(MemoryWav { _file: file, path })
+}
+
+/// memfd indisponible hors Linux.
+///
+/// # Errors
+/// - `IoError` toujours
+#[cfg(not(target_os = "linux"))]
+pub fn memfd_wav(_samples: &[f32], _sample_rate: u32) -> Result<MemoryWav, AppError> {
+    Err(AppError::IoError(
+        "In-memory audio files (memfd) require Linux".to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_take_once() {
+        let recordings = MemoryRecordings::default();
+        let id = recordings.insert(vec![0.1, -0.2]);
+
+        assert!(is_memory_id(&id));
+        assert_eq!(recordings.take(&id).as_deref(), Some(&[0.1, -0.2][..]));
+        assert!(recordings.take(&id).is_none(), "Samples handed over once");
+    }
+
+    #[test]
+    fn test_ids_are_unique() {
+        let recordings = MemoryRecordings::default();
+        let first = recordings.insert(Vec::new());
+        let second = recordings.insert(Vec::new());
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_clear_forgets_pending_recordings() {
+        let recordings = MemoryRecordings::default();
+        let id = recordings.insert(vec![0.5]);
+        recordings.clear();
+        assert!(recordings.take(&id).is_none());
+    }
+
+    #[test]
+    fn test_resample_to_16k() {
+        let samples: Vec<f32> = (0..48).map(|i| i as f32).collect();
+
+        let resampled = resample(samples.clone(), 48000, 16000);
+        assert_eq!(resampled.len(), 16);
+        assert_eq!(&resampled[..3], &[0.0, 3.0, 6.0]);
+
+        let upsampled = resample(vec![0.0, 1.0], 8000, 16000);
+        assert_eq!(upsampled, vec![0.0, 0.5, 1.0, 1.0]);
+
+        assert_eq!(resample(samples.clone(), 16000, 16000), samples);
+    }
+
+    #[test]
+    fn test_regular_paths_are_not_memory_ids() {
+        assert!(!is_memory_id("/home/user/.local/share/vocal-note-taker/temp/a.wav"));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_memfd_wav_round_trip_is_lossless() {
+        let samples = vec![0.123_456_7, -0.5, 1.0, 0.0];
+        let wav = memfd_wav(&samples, 16000).unwrap();
+
+        let mut reader = hound::WavReader::open(wav.path()).unwrap();
+        assert_eq!(reader.spec().sample_rate, 16000);
+        let read: Vec<f32> = reader.samples::<f32>().map(|s| s.unwrap()).collect();
+        assert_eq!(read, samples);
+
+        let path = wav.path().to_path_buf();
+        drop(wav);
+        assert!(!path.exists(), "memfd closed with its owner");
+    }
+}
@@ -3,10 +3,13 @@
 //! Submodules:
 //! - capture: cpal integration for microphone access
 //! - buffer: WAV file writing
+//! - memory: in-memory recordings for privacy mode (no WAV on disk)
 
 pub mod buffer;
 pub mod capture;
+pub mod memory;
 
 // Re-exports for convenience
 pub use buffer::{get_wav_path, save_wav};
 pub use capture::{start_recording, RecordingHandle, RecordingResult, DEFAULT_SAMPLE_RATE};
+pub use memory::MemoryRecordings;
@@ -4,15 +4,30 @@
 //! between the frontend and backend. Commands should be thin wrappers
 //! that delegate to domain modules.
 
-use std::path::PathBuf;
-use std::sync::Mutex;
-use tauri::{AppHandle, Emitter, State};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
 use tokio::sync::mpsc;
 
-use crate::audio::{self, RecordingHandle};
+use crate::audio::memory::{self as audio_memory, MemoryRecordings};
+use crate::audio::{self, RecordingHandle, DEFAULT_SAMPLE_RATE};
+use crate::config::AppConfig;
 use crate::error::AppError;
+use crate::note::{edit::ParsedCommand, EditCommand, NoteSnapshot, NoteState};
 use crate::system::{clipboard, shutdown};
-use crate::transcription::{get_model_path, transcribe_audio, WhisperModel, WhisperState};
+use crate::text::rules::{self as text_rules, ReplacementRule, RulesPreview};
+use crate::transcription::queue::Job;
+use crate::transcription::worker::{self, JobEvents};
+use crate::transcription::{
+    benchmark, chunking, load_model_if_needed, models, resolve_model_path, validate_options,
+    BenchmarkResult, CancelOutcome, ChunkResult, JobId, JobInfo, JobQueueState, ModelInfo,
+    RetentionState, Transcript, TranscriptionEngine, TranscriptionOptions, TranscriptionRequest,
+    UserFiles, WhisperModel, WhisperState,
+};
+
+/// Intervalle maximal entre deux vérifications d'inactivité du modèle
+const IDLE_CHECK_INTERVAL: Duration = Duration::from_secs(30);
 
 /// Validates that an audio path is within the allowed temp directory.
 /// NFR-SEC-3: Prevents path traversal attacks by ensuring audio files
@@ -35,16 +50,42 @@ fn validate_audio_path(path: &PathBuf) -> Result<(), AppError> {
     Ok(())
 }
 
+/// Vérifie un échantillon de benchmark comme un enregistrement à transcrire:
+/// fichier existant, extension .wav, dans le dossier temporaire de l'application.
+fn validate_sample_path(path: &str) -> Result<PathBuf, AppError> {
+    let path = PathBuf::from(path);
+    if !path.exists() {
+        return Err(AppError::TranscriptionFailed(format!(
+            "Fichier audio introuvable: {}",
+            path.display()
+        )));
+    }
+    if !path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("wav"))
+    {
+        return Err(AppError::InvalidAudioFormat(format!(
+            "L'échantillon doit être un fichier .wav: {}",
+            path.display()
+        )));
+    }
+    validate_audio_path(&path)?;
+    Ok(path)
+}
+
 /// State global pour le stream audio actif
 /// RecordingHandle est Send + Sync car il utilise des channels
 pub struct AudioState {
     pub recording: Mutex<Option<RecordingHandle>>,
+    /// Enregistrements gardés en mémoire ([privacy] in_memory)
+    pub memory: MemoryRecordings,
 }
 
 impl Default for AudioState {
     fn default() -> Self {
         Self {
             recording: Mutex::new(None),
+            memory: MemoryRecordings::default(),
         }
     }
 }
@@ -72,6 +113,9 @@ pub fn get_version(app: AppHandle) -> Result<String, AppError> {
 /// Returns `AppError` if cleanup fails.
 #[tauri::command]
 pub fn request_quit(app: AppHandle) -> Result<(), AppError> {
+    // Enregistrements en mémoire jamais transcrits ([privacy] in_memory)
+    app.state::<AudioState>().memory.clear();
+
     // Perform graceful shutdown cleanup
     shutdown::graceful_shutdown()?;
 
@@ -139,11 +183,12 @@ pub fn start_recording(
 /// Arrête l'enregistrement et sauvegarde le fichier WAV
 ///
 /// # Returns
-/// Chemin du fichier WAV créé
+/// Chemin du fichier WAV créé, ou identifiant `memory://` en mode confidentialité ([privacy] in_memory)
 ///
 /// # Errors
 /// - `RecordingInterrupted` si aucun enregistrement en cours
 /// - `IoError` si échec écriture fichier
+/// - `InvalidAudioFormat` en mode mémoire si la capture n'est pas à 16kHz
 #[tauri::command]
 pub async fn stop_recording(
     state: State<'_, AudioState>,
@@ -164,58 +209,249 @@ pub async fn stop_recording(
     // Arrêter et récupérer les samples avec le sample rate réel (async)
     let result = recording_handle.stop().await?;
 
-    // Sauvegarder en WAV avec le sample rate réel utilisé pendant la capture
-    let wav_path = audio::save_wav(&result.samples, result.sample_rate)?;
+    let duration_secs = result.samples.len() as f64 / result.sample_rate as f64;
+
+    let recording_id = if app.state::<AppConfig>().privacy.in_memory {
+        // Mode confidentialité: les samples restent en mémoire, aucun WAV sur disque
+        let samples =
+            audio_memory::resample(result.samples, result.sample_rate, DEFAULT_SAMPLE_RATE);
+        state.memory.insert(samples)
+    } else {
+        // Sauvegarder en WAV avec le sample rate réel utilisé pendant la capture
+        let wav_path = audio::save_wav(&result.samples, result.sample_rate)?;
+        wav_path.to_string_lossy().to_string()
+    };
 
     // Émettre event recording-stopped avec durée
-    let duration_secs = result.samples.len() as f64 / result.sample_rate as f64;
     let _ = app.emit("recording-stopped", duration_secs);
 
-    Ok(wav_path.to_string_lossy().to_string())
+    Ok(recording_id)
+}
+
+/// Abandonne un enregistrement arrêté sans le transcrire.
+///
+/// Libère un enregistrement en mémoire ([privacy] in_memory) ou supprime le
+/// fichier WAV (NFR-SEC-3).
+///
+/// # Arguments
+/// * `audio_path` - Valeur retournée par `stop_recording`
+///
+/// # Errors
+/// - `TranscriptionFailed` si l'enregistrement est introuvable
+#[tauri::command]
+pub fn discard_recording(audio: State<'_, AudioState>, audio_path: String) -> Result<(), AppError> {
+    if audio_memory::is_memory_id(&audio_path) {
+        return match audio.memory.take(&audio_path) {
+            Some(_) => Ok(()),
+            None => Err(AppError::TranscriptionFailed(format!(
+                "Enregistrement en mémoire introuvable: {}",
+                audio_path
+            ))),
+        };
+    }
+
+    let audio_path = PathBuf::from(&audio_path);
+    if !audio_path.exists() {
+        return Err(AppError::TranscriptionFailed(format!(
+            "Fichier audio introuvable: {}",
+            audio_path.display()
+        )));
+    }
+    validate_audio_path(&audio_path)?;
+    worker::cleanup_audio(&audio_path);
+    Ok(())
 }
 
 /// Payload for transcription progress events.
 #[derive(Clone, serde::Serialize)]
 struct ProgressPayload {
+    job_id: JobId,
     percent: i32,
 }
 
 /// Payload for transcription complete events.
+/// Flattens the structured transcript: `text` stays at the top level for
+/// existing listeners, alongside timed `segments` and metadata
+/// (`duration_ms`, `detected_language`, `source_language`, `translated`).
+/// `note` is the current note after this transcript (appended in append mode).
+/// `speaker_labels_dropped` is set when speaker turns were detected but could
+/// not be matched to the post-processed lines: the note then has no labels.
 #[derive(Clone, serde::Serialize)]
 struct TranscriptionPayload {
-    text: String,
+    job_id: JobId,
+    #[serde(flatten)]
+    transcript: Transcript,
+    note: NoteSnapshot,
+    speaker_labels_dropped: bool,
+}
+
+/// Payload for note edited events (voice editing command).
+#[derive(Clone, serde::Serialize)]
+struct NoteEditedPayload {
+    job_id: JobId,
+    command: EditCommand,
+    #[serde(flatten)]
+    note: NoteSnapshot,
+}
+
+/// Payload for model import progress events.
+#[derive(Clone, serde::Serialize)]
+struct ModelImportProgressPayload {
+    percent: i32,
+    bytes_copied: u64,
+    total_bytes: u64,
 }
 
 /// Payload for error events with audio cleanup information.
 /// Used when an error occurs during transcription and the audio file was deleted.
 /// NFR-SEC-3: Informs user that audio was deleted for privacy.
+/// `job_id` is null for errors outside a transcription job (model preload).
 #[derive(Clone, serde::Serialize)]
 struct ErrorWithCleanupPayload {
+    job_id: Option<JobId>,
     #[serde(rename = "type")]
-    error_type: String,
+    error_type: &'static str,
     message: String,
     audio_deleted: bool,
 }
 
-/// Lance la transcription de manière asynchrone.
+/// Payload for job-queued events.
+#[derive(Clone, serde::Serialize)]
+struct JobQueuedPayload {
+    job_id: JobId,
+    /// Position dans la file (0 = démarre immédiatement)
+    position: usize,
+}
+
+/// Payload for job-cancelled events.
+#[derive(Clone, serde::Serialize)]
+struct JobCancelledPayload {
+    job_id: JobId,
+    audio_deleted: bool,
+}
+
+/// Payload for recording-retained events.
+#[derive(Clone, serde::Serialize)]
+struct RecordingRetainedPayload {
+    job_id: JobId,
+    /// Délai avant suppression automatique de l'audio
+    expires_in_secs: u64,
+}
+
+/// Payload for recording-discarded events (retention window over).
+#[derive(Clone, serde::Serialize)]
+struct RecordingDiscardedPayload {
+    job_id: JobId,
+    audio_deleted: bool,
+}
+
+/// Payload for transcription-chunk events (long recordings).
+#[derive(Clone, serde::Serialize)]
+struct ChunkPayload {
+    job_id: JobId,
+    #[serde(flatten)]
+    chunk: ChunkResult,
+}
+
+/// Émet l'erreur d'une tâche avec l'information de cleanup.
+fn emit_error_with_cleanup(app: &AppHandle, job_id: JobId, error: &AppError, audio_deleted: bool) {
+    emit_error(app, Some(job_id), error, audio_deleted);
+}
+
+/// Émet une erreur hors tâche de transcription (aucun audio concerné).
+fn emit_app_error(app: &AppHandle, error: &AppError) {
+    emit_error(app, None, error, false);
+}
+
+fn emit_error(app: &AppHandle, job_id: Option<JobId>, error: &AppError, audio_deleted: bool) {
+    let payload = ErrorWithCleanupPayload {
+        job_id,
+        error_type: error.kind(),
+        message: error.to_string(),
+        audio_deleted,
+    };
+    let _ = app.emit("error", payload);
+}
+
+/// Ajoute un enregistrement à la file de transcription.
 ///
-/// Retourne immédiatement - résultat via événements:
-/// - transcription-progress: { percent: 0-100 }
-/// - transcription-complete: { text: "..." }
-/// - error: { type: "...", message: "..." }
+/// Retourne immédiatement l'ID de la tâche - résultat via événements,
+/// tous porteurs de `job_id`:
+/// - job-queued: { job_id, position }
+/// - transcription-progress: { job_id, percent: 0-100 }
+/// - transcription-chunk: { job_id, index, total, start_ms, end_ms, text, segments } (long recordings)
+/// - transcription-complete: { job_id, text, segments, duration_ms, detected_language, source_language, translated, note }
+/// - note-edited: { job_id, command, text, can_undo } (commande vocale d'édition, voir [note])
+/// - job-cancelled: { job_id, audio_deleted }
+/// - error: { job_id, type: "...", message: "...", audio_deleted }
+/// - recording-retained: { job_id, expires_in_secs } (rétention activée, voir `retranscribe`)
+/// - recording-discarded: { job_id, audio_deleted } (fenêtre de rétention expirée ou remplacée)
 ///
 /// # Arguments
-/// * `audio_path` - Chemin vers le fichier WAV à transcrire
+/// * `audio_path` - Chemin vers le fichier WAV à transcrire, ou identifiant `memory://`
+/// * `request` - Paramètres optionnels (profil, langue, traduction, modèle) prioritaires sur config.toml
 ///
 /// # Errors
-/// - `TranscriptionFailed` si le fichier audio n'existe pas
+/// - `TranscriptionFailed` si le fichier (ou l'enregistrement en mémoire) n'existe pas ou est déjà en file
+/// - `ConfigurationError` si le profil demandé n'existe pas, si la langue n'est pas
+///   supportée, si la traduction est demandée avec un modèle anglais uniquement ou
+///   les tours de parole avec un modèle sans tinydiarize
+/// - `ModelNotFound` si le modèle demandé n'est pas installé
 #[tauri::command]
 pub async fn start_transcription(
     app: AppHandle,
-    whisper_state: State<'_, WhisperState>,
     audio_path: String,
-) -> Result<(), AppError> {
-    let audio_path = PathBuf::from(&audio_path);
+    request: Option<TranscriptionRequest>,
+) -> Result<JobId, AppError> {
+    submit_recording(&app, &audio_path, request)
+}
+
+/// Met un enregistrement en file: `start_transcription`, et l'arrêt par
+/// raccourci global (fenêtre masquée, aucun appel du frontend).
+///
+/// # Errors
+/// Voir `start_transcription`.
+pub fn submit_recording(
+    app: &AppHandle,
+    audio_path: &str,
+    request: Option<TranscriptionRequest>,
+) -> Result<JobId, AppError> {
+    // Résoudre langue/profil avant la mise en file pour remonter les erreurs de config
+    // directement. En cas d'erreur, l'enregistrement est gardé: la requête corrigée
+    // peut être renvoyée (le nettoyage au démarrage et à l'arrêt s'applique toujours).
+    let config = app.state::<AppConfig>();
+    let files = app.state::<UserFiles>();
+    let options = resolve_options(&config, &files, request)?;
+
+    let jobs = app.state::<JobQueueState>();
+    let job_id = enqueue_recording(&jobs, &app.state::<AudioState>(), audio_path, options)?;
+    emit_job_queued(app, &jobs, job_id);
+    Ok(job_id)
+}
+
+/// Ajoute à la file un enregistrement sur disque ou en mémoire, options résolues.
+///
+/// # Errors
+/// - `TranscriptionFailed` si l'enregistrement n'existe pas, est hors du dossier
+///   temporaire de l'application ou est déjà en file
+pub fn enqueue_recording(
+    jobs: &JobQueueState,
+    audio: &AudioState,
+    audio_path: &str,
+    options: TranscriptionOptions,
+) -> Result<JobId, AppError> {
+    if audio_memory::is_memory_id(audio_path) {
+        // Enregistrement en mémoire: remis une seule fois à sa tâche
+        let samples = audio.memory.take(audio_path).ok_or_else(|| {
+            AppError::TranscriptionFailed(format!(
+                "Enregistrement en mémoire introuvable: {}",
+                audio_path
+            ))
+        })?;
+        return jobs.submit_samples(PathBuf::from(audio_path), samples, options);
+    }
+
+    let audio_path = PathBuf::from(audio_path);
 
     // Vérifier que le fichier existe
     if !audio_path.exists() {
@@ -228,108 +464,632 @@ pub async fn start_transcription(
     // NFR-SEC-3: Validate path is within allowed temp directory (prevents path traversal)
     validate_audio_path(&audio_path)?;
 
-    // Clone les éléments nécessaires pour le spawn
-    let model_arc = whisper_state.model.clone();
-    let app_clone = app.clone();
+    // Pas de suppression ici si déjà en file: le fichier appartient à la tâche existante
+    jobs.submit(audio_path, options)
+}
+
+/// Résout les paramètres d'une requête et les vérifie avant la mise en file:
+/// modèle demandé installé, langue connue, traduction et tours de parole
+/// possibles avec ce modèle.
+fn resolve_options(
+    config: &AppConfig,
+    files: &UserFiles,
+    request: Option<TranscriptionRequest>,
+) -> Result<TranscriptionOptions, AppError> {
+    let options = TranscriptionOptions::resolve(config, files, &request.unwrap_or_default())?;
+    let model_path = resolve_model_path(options.model.as_deref())?;
+    validate_options(&options, &model_path)?;
+    Ok(options)
+}
+
+/// Émet `job-queued` avec la position de la tâche dans la file.
+fn emit_job_queued(app: &AppHandle, jobs: &JobQueueState, job_id: JobId) {
+    let position = jobs
+        .queue
+        .lock()
+        .expect("Job queue lock poisoned")
+        .position(job_id)
+        .unwrap_or(0);
+    let _ = app.emit("job-queued", JobQueuedPayload { job_id, position });
+}
+
+/// Liste les tâches de transcription actives (en cours puis en file).
+#[tauri::command]
+pub fn list_jobs(jobs: State<'_, JobQueueState>) -> Vec<JobInfo> {
+    jobs.queue.lock().expect("Job queue lock poisoned").list()
+}
+
+/// Annule une tâche de transcription.
+///
+/// Une tâche en file est retirée et son audio supprimé immédiatement.
+/// Une tâche en cours est interrompue par whisper.cpp; `job-cancelled` est
+/// émis par le worker quand elle s'arrête, après suppression de son audio et
+/// de son checkpoint (NFR-SEC-3): une annulation n'est jamais reprise.
+///
+/// # Errors
+/// - `TranscriptionFailed` si aucune tâche active ne porte cet ID
+#[tauri::command]
+pub fn cancel_job(
+    app: AppHandle,
+    jobs: State<'_, JobQueueState>,
+    job_id: JobId,
+) -> Result<(), AppError> {
+    let outcome = jobs
+        .queue
+        .lock()
+        .expect("Job queue lock poisoned")
+        .cancel(job_id)?;
+
+    if let CancelOutcome::Removed(job) = outcome {
+        let audio_deleted = worker::discard_audio(&job);
+        let _ = app.emit(
+            "job-cancelled",
+            JobCancelledPayload {
+                job_id,
+                audio_deleted,
+            },
+        );
+    }
+
+    Ok(())
+}
+
+/// Abandonne une transcription interrompue (crash, arrêt) au lieu de la reprendre.
+///
+/// Les transcriptions interrompues sont remises en file au démarrage; celle
+/// de cet enregistrement est annulée comme par `cancel_job`. Si elle n'est
+/// pas en file, l'audio et le checkpoint sont supprimés directement.
+///
+/// # Arguments
+/// * `audio_path` - Enregistrement à abandonner (`audio_path` de `list_jobs`)
+///
+/// # Errors
+/// - `TranscriptionFailed` si ce n'est pas une transcription interrompue
+#[tauri::command]
+pub fn discard_resumable_job(
+    app: AppHandle,
+    jobs: State<'_, JobQueueState>,
+    audio_path: String,
+) -> Result<(), AppError> {
+    let audio_path = PathBuf::from(audio_path);
+    let resumable = chunking::find_resumable(&crate::audio::buffer::get_temp_dir())
+        .iter()
+        .any(|(path, _)| *path == audio_path);
+    if !resumable {
+        return Err(AppError::TranscriptionFailed(format!(
+            "Aucune transcription interrompue pour {}",
+            audio_path.display()
+        )));
+    }
+
+    let job_id = jobs
+        .queue
+        .lock()
+        .expect("Job queue lock poisoned")
+        .find_by_audio(&audio_path);
+    match job_id {
+        Some(job_id) => cancel_job(app, jobs, job_id),
+        None => {
+            worker::discard_job_files(&audio_path);
+            Ok(())
+        }
+    }
+}
+
+/// Relance la transcription d'un enregistrement conservé ([privacy] retention_secs).
+///
+/// Les paramètres sont résolus comme pour `start_transcription` (requête >
+/// profil > config.toml), avec en plus le choix du modèle. Le résultat réussi
+/// est à nouveau conservé, pour une nouvelle fenêtre de rétention.
+///
+/// # Arguments
+/// * `job_id` - Tâche dont le résultat est à refaire
+/// * `request` - Nouveaux paramètres (modèle, langue, prompt...)
+///
+/// # Errors
+/// - `TranscriptionFailed` si l'enregistrement de cette tâche n'est plus conservé
+/// - `ConfigurationError` si le profil demandé n'existe pas
+/// - `ModelNotFound` si le modèle demandé n'est pas installé
+#[tauri::command]
+pub async fn retranscribe(
+    app: AppHandle,
+    jobs: State<'_, JobQueueState>,
+    config: State<'_, AppConfig>,
+    files: State<'_, UserFiles>,
+    retention: State<'_, RetentionState>,
+    job_id: JobId,
+    request: Option<TranscriptionRequest>,
+) -> Result<JobId, AppError> {
+    // Résoudre avant de reprendre l'audio: une erreur de paramètres le laisse conservé
+    let options = resolve_options(&config, &files, request)?;
+
+    let retained = retention.take(job_id).ok_or_else(|| {
+        AppError::TranscriptionFailed(format!(
+            "Enregistrement de la tâche {} non conservé ou expiré",
+            job_id
+        ))
+    })?;
+
+    let new_job_id = match retained.samples {
+        Some(samples) => jobs.submit_samples(retained.audio_path, samples, options)?,
+        None => jobs.submit(retained.audio_path, options)?,
+    };
+    emit_job_queued(&app, &jobs, new_job_id);
+
+    Ok(new_job_id)
+}
+
+/// Accepte le résultat d'une tâche: son enregistrement conservé est supprimé.
+///
+/// # Returns
+/// true si l'audio a été supprimé, false s'il n'était plus conservé
+/// (fenêtre expirée, remplacé ou déjà re-transcrit).
+#[tauri::command]
+pub fn accept_transcription(retention: State<'_, RetentionState>, job_id: JobId) -> bool {
+    match retention.take(job_id) {
+        Some(job) => worker::discard_audio(&job),
+        None => false,
+    }
+}
+
+/// Essaie des règles de remplacement sur un texte d'exemple.
+///
+/// Sans `rules`, utilise les règles chargées au démarrage (même si
+/// `apply_rules` est désactivé).
+///
+/// # Arguments
+/// * `text` - Texte d'exemple
+/// * `rules` - Règles à essayer avant de les enregistrer (optionnel)
+///
+/// # Errors
+/// - `ConfigurationError` si une règle est invalide
+#[tauri::command]
+pub fn preview_rules(
+    files: State<'_, UserFiles>,
+    text: String,
+    rules: Option<Vec<ReplacementRule>>,
+) -> Result<RulesPreview, AppError> {
+    let preview = match rules {
+        Some(rules) => text_rules::preview(&text, &text_rules::compile_rules(&rules)?),
+        None => text_rules::preview(&text, files.rules.compiled()),
+    };
+    Ok(preview)
+}
+
+/// Retourne la note courante.
+#[tauri::command]
+pub fn get_note(notes: State<'_, NoteState>) -> NoteSnapshot {
+    notes.snapshot()
+}
+
+/// Annule la dernière modification de la note (transcription ou commande vocale).
+///
+/// # Errors
+/// - `NoteEditFailed` s'il n'y a rien à annuler
+#[tauri::command]
+pub fn undo_note(notes: State<'_, NoteState>) -> Result<NoteSnapshot, AppError> {
+    notes.execute(&EditCommand::Undo, "")
+}
+
+/// Commence une nouvelle note (mode ajout): les prochaines transcriptions
+/// ne s'ajoutent plus à l'ancienne, récupérable par `undo_note`.
+///
+/// Émet note-cleared: { text, can_undo } pour la fenêtre, même cachée.
+#[tauri::command]
+pub fn new_note(app: AppHandle, notes: State<'_, NoteState>) -> NoteSnapshot {
+    let note = notes.clear();
+    let _ = app.emit("note-cleared", note.clone());
+    note
+}
+
+/// Remet en file les transcriptions découpées interrompues (crash, arrêt).
+///
+/// Une transcription annulée n'a plus de checkpoint; une transcription
+/// interrompue dont on ne veut plus s'abandonne par `discard_resumable_job`.
+pub fn resume_interrupted_jobs(app: &AppHandle) {
+    let jobs = app.state::<JobQueueState>();
+    for (audio_path, options) in chunking::find_resumable(&crate::audio::buffer::get_temp_dir()) {
+        println!("Resuming interrupted transcription: {}", audio_path.display());
+        match jobs.submit(audio_path, options) {
+            Ok(job_id) => emit_job_queued(app, &jobs, job_id),
+            Err(e) => eprintln!("Warning: Could not resume transcription: {:?}", e),
+        }
+    }
+}
 
-    // Spawn async task pour ne pas bloquer
-    tokio::spawn(async move {
-        // Helper fonction pour cleanup (appelée dans tous les chemins)
-        // Retourne true si le fichier a été supprimé
-        let cleanup_audio = |path: &PathBuf| -> bool {
-            // NFR-SEC-1, NFR-SEC-3: Cleanup immédiat du fichier audio temporaire (privacy-first)
-            if let Err(e) = std::fs::remove_file(path) {
-                eprintln!("Warning: Failed to cleanup temp audio file: {:?}", e);
-                false
-            } else {
-                println!("Temp audio file cleaned up: {}", path.display());
-                true
+/// Événements Tauri des tâches de transcription.
+struct TauriJobEvents {
+    app: AppHandle,
+}
+
+impl JobEvents for TauriJobEvents {
+    fn progress(&self, job_id: JobId, percent: i32) {
+        let _ = self.app.emit(
+            "transcription-progress",
+            ProgressPayload { job_id, percent },
+        );
+    }
+
+    fn chunk(&self, job_id: JobId, chunk: &ChunkResult) {
+        let _ = self.app.emit(
+            "transcription-chunk",
+            ChunkPayload {
+                job_id,
+                chunk: chunk.clone(),
+            },
+        );
+    }
+
+    fn complete(&self, job_id: JobId, transcript: Transcript, _audio_deleted: bool) {
+        let notes = self.app.state::<NoteState>();
+        let config = self.app.state::<AppConfig>();
+
+        // La note est affichée et copiée: libellés des locuteurs rendus ici
+        let labelled = transcript.labelled_text();
+        let speaker_labels_dropped = labelled.is_none();
+        if speaker_labels_dropped {
+            eprintln!(
+                "Warning: job {}: speaker turns no longer match the text lines, labels dropped",
+                job_id
+            );
+        }
+        let text = labelled.unwrap_or_else(|| transcript.text.clone());
+        let note = notes.add_transcript(text, &config.note);
+        let _ = self.app.emit(
+            "transcription-complete",
+            TranscriptionPayload {
+                job_id,
+                transcript,
+                note,
+                speaker_labels_dropped,
+            },
+        );
+    }
+
+    fn edit(&self, job_id: JobId, command: ParsedCommand, audio_deleted: bool) {
+        let notes = self.app.state::<NoteState>();
+        match notes.execute(&command.command, &command.rest) {
+            Ok(note) => {
+                let _ = self.app.emit(
+                    "note-edited",
+                    NoteEditedPayload {
+                        job_id,
+                        command: command.command,
+                        note,
+                    },
+                );
             }
-        };
+            Err(e) => emit_error_with_cleanup(&self.app, job_id, &e, audio_deleted),
+        }
+    }
 
-        // Helper pour émettre erreur avec info cleanup
-        let emit_error_with_cleanup = |app: &AppHandle, error: &AppError, audio_deleted: bool| {
-            let error_type = match error {
-                AppError::MicrophoneAccessDenied => "MicrophoneAccessDenied",
-                AppError::MicrophoneNotFound => "MicrophoneNotFound",
-                AppError::TranscriptionFailed(_) => "TranscriptionFailed",
-                AppError::RecordingInterrupted => "RecordingInterrupted",
-                AppError::ConfigurationError(_) => "ConfigurationError",
-                AppError::ClipboardError => "ClipboardError",
-                AppError::IoError(_) => "IoError",
-                AppError::HotkeyRegistrationFailed(_) => "HotkeyRegistrationFailed",
-                AppError::ModelNotFound(_) => "ModelNotFound",
-                AppError::ModelLoadFailed(_) => "ModelLoadFailed",
-                AppError::InvalidAudioFormat(_) => "InvalidAudioFormat",
-            };
-
-            let payload = ErrorWithCleanupPayload {
-                error_type: error_type.to_string(),
-                message: error.to_string(),
+    fn cancelled(&self, job_id: JobId, audio_deleted: bool) {
+        let _ = self.app.emit(
+            "job-cancelled",
+            JobCancelledPayload {
+                job_id,
                 audio_deleted,
-            };
-            let _ = app.emit("error", payload);
+            },
+        );
+    }
+
+    fn failed(&self, job_id: JobId, error: &AppError, audio_deleted: bool) {
+        emit_error_with_cleanup(&self.app, job_id, error, audio_deleted);
+    }
+
+    fn keep_audio(&self, job: &Job) -> bool {
+        let window = match self.app.state::<AppConfig>().privacy.retention() {
+            Some(window) => window,
+            None => return false,
         };
 
-        // Émettre progression initiale
-        let _ = app_clone.emit("transcription-progress", ProgressPayload { percent: 0 });
-
-        // Charger le modèle si nécessaire (lazy loading)
-        let mut model_guard = model_arc.lock().await;
-        if model_guard.is_none() {
-            let _ = app_clone.emit("transcription-progress", ProgressPayload { percent: 5 });
-
-            match get_model_path() {
-                Ok(model_path) => match WhisperModel::load(&model_path) {
-                    Ok(model) => {
-                        println!("Model loaded successfully");
-                        *model_guard = Some(model);
-                    }
-                    Err(e) => {
-                        eprintln!("Failed to load model: {:?}", e);
-                        let deleted = cleanup_audio(&audio_path);
-                        emit_error_with_cleanup(&app_clone, &e, deleted);
-                        return;
-                    }
-                },
-                Err(e) => {
-                    eprintln!("Failed to get model path: {:?}", e);
-                    let deleted = cleanup_audio(&audio_path);
-                    emit_error_with_cleanup(&app_clone, &e, deleted);
-                    return;
-                }
+        // Un seul enregistrement conservé: le précédent est supprimé
+        if let Some(previous) = self.app.state::<RetentionState>().keep(job.clone(), window) {
+            discard_retained(&self.app, &previous);
+        }
+        let _ = self.app.emit(
+            "recording-retained",
+            RecordingRetainedPayload {
+                job_id: job.id,
+                expires_in_secs: window.as_secs(),
+            },
+        );
+
+        let app = self.app.clone();
+        tauri::async_runtime::spawn(async move {
+            tokio::time::sleep(window).await;
+            if let Some(expired) = app.state::<RetentionState>().take_expired(Instant::now()) {
+                discard_retained(&app, &expired);
             }
+        });
+        true
+    }
+}
+
+/// Supprime l'audio d'un enregistrement conservé et émet `recording-discarded`.
+fn discard_retained(app: &AppHandle, job: &Job) {
+    let audio_deleted = worker::discard_audio(job);
+    println!("Retained recording of job {} discarded", job.id);
+    let _ = app.emit(
+        "recording-discarded",
+        RecordingDiscardedPayload {
+            job_id: job.id,
+            audio_deleted,
+        },
+    );
+}
+
+/// Démarre le worker unique qui traite la file de transcription dans l'ordre.
+///
+/// # Arguments
+/// * `engine` - Moteur de transcription (`WhisperEngine` en production)
+pub fn spawn_job_worker(app: AppHandle, engine: Arc<dyn TranscriptionEngine>) {
+    let events = Arc::new(TauriJobEvents { app: app.clone() });
+    tauri::async_runtime::spawn(async move {
+        let jobs = app.state::<JobQueueState>();
+        loop {
+            let job = jobs.next_job().await;
+            let job_id = job.id;
+
+            // Transcription CPU-intensive: hors du runtime async
+            let engine = Arc::clone(&engine);
+            let events = Arc::clone(&events);
+            let result = tauri::async_runtime::spawn_blocking(move || {
+                worker::run_job(engine.as_ref(), events.as_ref(), &job)
+            })
+            .await;
+            if let Err(e) = result {
+                eprintln!("Transcription job {} panicked: {:?}", job_id, e);
+            }
+
+            jobs.finish(job_id);
         }
+    });
+}
 
-        let _ = app_clone.emit("transcription-progress", ProgressPayload { percent: 10 });
+/// Payload for model-loading event.
+#[derive(Clone, serde::Serialize)]
+pub struct ModelLoadingPayload {
+    pub path: String,
+}
 
-        // Transcription (cette partie est CPU-intensive)
-        // Note: whisper-rs ne supporte pas les callbacks de progression natifs
-        // On simule avec des étapes discrètes
-        if let Some(ref model) = *model_guard {
-            let _ = app_clone.emit("transcription-progress", ProgressPayload { percent: 20 });
+/// Payload for model-ready event.
+#[derive(Clone, serde::Serialize)]
+pub struct ModelReadyPayload {
+    /// Durée du chargement (0 si le modèle était déjà chargé)
+    pub load_ms: u64,
+}
 
-            match transcribe_audio(model, &audio_path) {
-                Ok(text) => {
-                    let _ = app_clone.emit("transcription-progress", ProgressPayload { percent: 100 });
-                    let _ = app_clone.emit(
-                        "transcription-complete",
-                        TranscriptionPayload { text },
-                    );
-                }
-                Err(e) => {
-                    eprintln!("Transcription failed: {:?}", e);
-                    let deleted = cleanup_audio(&audio_path);
-                    emit_error_with_cleanup(&app_clone, &e, deleted);
-                    return; // Ne pas faire double cleanup
-                }
+/// Précharge le modèle Whisper en arrière-plan ([model] preload).
+///
+/// Le verrou du modèle est pris avant de rendre la main: une transcription
+/// demandée pendant le préchargement attend sa fin au lieu de lancer un
+/// second chargement.
+///
+/// Events emitted:
+/// - model-loading: { path }
+/// - model-ready: { load_ms }
+/// - error: { job_id: null, type, message, audio_deleted: false } si le modèle est absent ou illisible
+pub fn spawn_model_preload(app: AppHandle) {
+    let model_arc = app.state::<WhisperState>().model.clone();
+    let Ok(mut model_guard) = model_arc.try_lock_owned() else {
+        // Déjà verrouillé: un chargement ou une transcription est en cours
+        return;
+    };
+
+    let model_name = app.state::<AppConfig>().model.name.clone();
+
+    // Chargement bloquant (plusieurs Go): hors du runtime async, comme le worker
+    tauri::async_runtime::spawn_blocking(move || {
+        let model_path = match resolve_model_path(model_name.as_deref()) {
+            Ok(path) => path,
+            Err(e) => {
+                eprintln!("Model preload failed: {:?}", e);
+                emit_app_error(&app, &e);
+                return;
             }
+        };
+        if model_guard.as_ref().is_none_or(|model| model.path() != model_path) {
+            let path = model_path.display().to_string();
+            let _ = app.emit("model-loading", ModelLoadingPayload { path });
         }
 
-        // Cleanup TOUJOURS exécuté (succès de transcription)
-        // Placé hors du bloc if let Some(ref model) pour garantir exécution
-        cleanup_audio(&audio_path);
+        let started = Instant::now();
+        match load_model_if_needed(&mut model_guard, &model_path) {
+            Ok(loaded) => {
+                let load_ms = if loaded {
+                    started.elapsed().as_millis() as u64
+                } else {
+                    0
+                };
+                println!("Model preloaded in {} ms", load_ms);
+                let _ = app.emit("model-ready", ModelReadyPayload { load_ms });
+            }
+            Err(e) => {
+                eprintln!("Model preload failed: {:?}", e);
+                emit_app_error(&app, &e);
+            }
+        }
     });
+}
 
-    Ok(())
+/// Recharge un modèle déchargé temporairement, avec les événements du préchargement.
+fn reload_model(app: &AppHandle, slot: &mut Option<WhisperModel>, path: &Path) {
+    let _ = app.emit(
+        "model-loading",
+        ModelLoadingPayload {
+            path: path.display().to_string(),
+        },
+    );
+    let started = Instant::now();
+    match load_model_if_needed(slot, path) {
+        Ok(_) => {
+            let load_ms = started.elapsed().as_millis() as u64;
+            let _ = app.emit("model-ready", ModelReadyPayload { load_ms });
+        }
+        Err(e) => {
+            eprintln!("Model reload failed: {:?}", e);
+            emit_app_error(app, &e);
+        }
+    }
+}
+
+/// Payload for model-unloaded event.
+#[derive(Clone, serde::Serialize)]
+pub struct ModelUnloadedPayload {
+    pub idle_timeout_secs: u64,
+}
+
+/// Surveille l'inactivité du modèle Whisper et le décharge après `timeout`.
+///
+/// Vérifie périodiquement (au plus toutes les 30s) puis émet `model-unloaded`.
+/// Le modèle est rechargé à la demande par `start_transcription`.
+pub fn spawn_idle_unloader(app: AppHandle, timeout: Duration) {
+    let check_interval = timeout.min(IDLE_CHECK_INTERVAL);
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(check_interval).await;
+
+            let whisper_state = app.state::<WhisperState>();
+            if whisper_state.unload_if_idle(timeout) {
+                let _ = app.emit(
+                    "model-unloaded",
+                    ModelUnloadedPayload {
+                        idle_timeout_secs: timeout.as_secs(),
+                    },
+                );
+            }
+        }
+    });
+}
+
+/// Importe un modèle Whisper depuis un fichier local (clé USB, partage réseau monté...).
+///
+/// Copie le fichier dans le dossier des modèles, le vérifie puis l'enregistre.
+/// Aucun accès réseau (NFR-SEC-1).
+///
+/// Événements émis:
+/// - model-import-progress: { percent, bytes_copied, total_bytes }
+/// - model-imported: ModelInfo
+///
+/// # Arguments
+/// * `source_path` - Chemin du fichier modèle `.bin` à importer
+///
+/// # Errors
+/// - `ModelImportFailed` si le fichier n'est pas un modèle GGML valide,
+///   si un modèle du même nom existe déjà, ou si la copie échoue
+#[tauri::command]
+pub async fn import_model(app: AppHandle, source_path: String) -> Result<ModelInfo, AppError> {
+    let source = PathBuf::from(&source_path);
+    let app_clone = app.clone();
+
+    // Copie de plusieurs GB: exécutée hors du runtime async
+    let info = tokio::task::spawn_blocking(move || {
+        let mut last_percent = -1;
+        models::import_model(&source, |bytes_copied, total_bytes| {
+            let percent = (bytes_copied * 100 / total_bytes.max(1)) as i32;
+            // N'émettre qu'à chaque point de pourcentage pour ne pas saturer l'IPC
+            if percent != last_percent {
+                last_percent = percent;
+                let _ = app_clone.emit(
+                    "model-import-progress",
+                    ModelImportProgressPayload {
+                        percent,
+                        bytes_copied,
+                        total_bytes,
+                    },
+                );
+            }
+        })
+    })
+    .await
+    .map_err(|e| AppError::ModelImportFailed(format!("Tâche d'import interrompue: {}", e)))??;
+
+    let _ = app.emit("model-imported", info.clone());
+
+    Ok(info)
+}
+
+/// Payload for benchmark-progress events.
+#[derive(Clone, serde::Serialize)]
+pub struct BenchmarkProgressPayload {
+    /// Numéro de la mesure terminée (1-based)
+    pub index: usize,
+    pub total: usize,
+    pub result: BenchmarkResult,
+}
+
+/// Compare localement les modèles installés et les nombres de threads.
+///
+/// Transcrit le même échantillon avec chaque modèle et chaque nombre de threads,
+/// et mesure chargement, décodage et facteur temps réel. Le modèle de
+/// transcription est déchargé pendant la mesure (mémoire et CPU réservés au
+/// benchmark), puis rechargé à la fin.
+///
+/// Événements émis:
+/// - benchmark-progress: { index, total, result }
+/// - model-loading: { path } et model-ready: { load_ms } au rechargement du modèle
+///
+/// # Arguments
+/// * `sample_path` - WAV 16kHz mono du dossier temporaire de l'application
+///   (sinon signal généré de 30s)
+/// * `thread_counts` - Nombres de threads à essayer (sinon 1, 2, 4... jusqu'au nombre de cœurs)
+///
+/// # Errors
+/// - `ConfigurationError` si aucun modèle n'est installé
+/// - `TranscriptionFailed` si l'échantillon n'existe pas ou est hors du dossier temporaire
+/// - `InvalidAudioFormat` si l'échantillon fourni n'est pas un WAV 16kHz mono
+///
+/// Une mesure en échec n'interrompt pas le benchmark: son résultat porte `error`.
+#[tauri::command]
+pub async fn benchmark_model(
+    app: AppHandle,
+    whisper_state: State<'_, WhisperState>,
+    config: State<'_, AppConfig>,
+    files: State<'_, UserFiles>,
+    sample_path: Option<String>,
+    thread_counts: Option<Vec<usize>>,
+) -> Result<Vec<BenchmarkResult>, AppError> {
+    let options =
+        TranscriptionOptions::resolve(&config, &files, &TranscriptionRequest::default())?;
+    let sample_path = sample_path
+        .map(|path| validate_sample_path(&path))
+        .transpose()?;
+    let models = models::list_installed_models()?;
+    let thread_counts = thread_counts.unwrap_or_else(benchmark::default_thread_counts);
+
+    // Pas de transcription concurrente pendant la mesure
+    let mut model_guard = whisper_state.model.clone().lock_owned().await;
+    let resident = model_guard.take().map(|model| model.path().to_path_buf());
+
+    let app_clone = app.clone();
+    tokio::task::spawn_blocking(move || {
+        let results = benchmark::load_sample(sample_path.as_deref()).and_then(|samples| {
+            benchmark::run_benchmark(
+                &models,
+                &samples,
+                &thread_counts,
+                &options,
+                |result, index, total| {
+                    let _ = app_clone.emit(
+                        "benchmark-progress",
+                        BenchmarkProgressPayload {
+                            index,
+                            total,
+                            result: result.clone(),
+                        },
+                    );
+                },
+            )
+        });
+        // Modèle résident rechargé: la prochaine transcription ne paie pas le chargement
+        if let Some(path) = resident {
+            reload_model(&app_clone, &mut model_guard, &path);
+        }
+        drop(model_guard);
+        results
+    })
+    .await
+    .map_err(|e| AppError::TranscriptionFailed(format!("Benchmark interrompu: {}", e)))?
 }
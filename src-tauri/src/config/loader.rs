@@ -1,6 +1,1218 @@
 //! Configuration loader module - TOML parsing
 //!
-//! Placeholder for future implementation:
-//! - Load config from ~/.config/vocal-note-taker/config.toml
-//! - Default values for missing fields
-//! - Validation of configuration values
+//! Loads ~/.config/vocal-note-taker/config.toml at startup.
+//! Missing file or missing fields fall back to defaults.
+//! Read-only for MVP: edited manually, reload requires a restart.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::error::AppError;
+
+/// Root of config.toml.
+///
+/// ```toml
+/// active_profile = "reunion"
+///
+/// [transcription]
+/// language = "fr"
+/// n_threads = 8
+///
+/// [profiles.reunion]
+/// language = "en"
+///
+/// [profiles.notes-en]
+/// translate = true
+///
+/// [profiles.interview]
+/// diarize = true
+///
+/// [model]
+/// name = "ggml-medium.bin"
+/// preload = true
+/// idle_timeout_secs = 600
+///
+/// [privacy]
+/// in_memory = true
+/// retention_secs = 120
+///
+/// [postprocess]
+/// enabled = true
+/// remove_fillers = true
+///
+/// [note]
+/// voice_commands = true
+/// append = true
+/// separator = "paragraph"
+///
+/// [postprocess.languages.fr]
+/// fillers = ["euh", "ben", "bah"]
+///
+/// [glossary]
+/// terms = ["Tamles", "vocal-note-taker"]
+///
+/// [glossary.groups]
+/// equipe = ["Amélie", "Joachim"]
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+    /// Profil appliqué quand la requête n'en précise pas
+    pub active_profile: Option<String>,
+    /// Réglages de transcription par défaut
+    pub transcription: TranscriptionConfig,
+    /// Profils nommés surchargeant les réglages par défaut
+    pub profiles: HashMap<String, ProfileConfig>,
+    /// Vocabulaire personnalisé injecté dans le prompt initial de Whisper
+    pub glossary: GlossaryConfig,
+    /// Gestion du modèle Whisper en mémoire
+    pub model: ModelConfig,
+    /// Confidentialité de l'audio enregistré
+    pub privacy: PrivacyConfig,
+    /// Corrections appliquées au texte transcrit
+    pub postprocess: PostProcessConfig,
+    /// Note courante et édition vocale
+    pub note: NoteConfig,
+}
+
+/// Section [transcription] - default transcription settings.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct TranscriptionConfig {
+    /// Code langue ISO 639-1 ("fr", "en"...) ou "auto" pour l'auto-détection
+    pub language: String,
+    /// Traduire vers l'anglais (tâche "translate" de Whisper) au lieu de transcrire
+    pub translate: bool,
+    /// Threads CPU pour Whisper (absent = automatique, 4 au plus)
+    pub n_threads: Option<usize>,
+    /// Détection des changements de locuteur (tinydiarize, nécessite un modèle "tdrz":
+    /// refusée avant la mise en file pour les autres modèles)
+    pub diarize: bool,
+    /// Stratégie de décodage
+    pub decoding: DecodingConfig,
+    /// Découpage des enregistrements longs
+    pub chunking: ChunkingConfig,
+    /// Filtrage des hallucinations après décodage
+    pub filter: FilterConfig,
+}
+
+impl Default for TranscriptionConfig {
+    fn default() -> Self {
+        Self {
+            language: "auto".to_string(),
+            translate: false,
+            n_threads: None,
+            diarize: false,
+            decoding: DecodingConfig::default(),
+            chunking: ChunkingConfig::default(),
+            filter: FilterConfig::default(),
+        }
+    }
+}
+
+/// Décodage Whisper: greedy (rapide) ou beam search (plus précis, plus lent).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecodingStrategy {
+    Greedy,
+    BeamSearch,
+}
+
+/// Section [transcription.decoding] - decoding strategy and temperature fallback.
+///
+/// ```toml
+/// [transcription.decoding]
+/// strategy = "beam_search"
+/// beam_size = 5
+/// temperature_increment = 0.2
+/// alternatives = 2
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DecodingConfig {
+    pub strategy: DecodingStrategy,
+    /// Nombre de candidats échantillonnés en greedy
+    pub best_of: i32,
+    /// Largeur du faisceau en beam search
+    pub beam_size: i32,
+    /// Température initiale (0.0 = décodage le plus probable)
+    pub temperature: f32,
+    /// Pas de remontée de température quand un segment échoue (0.0 = pas de repli)
+    pub temperature_increment: f32,
+    /// Réglages fixes pour des résultats reproductibles; ignore les champs ci-dessus
+    pub deterministic: bool,
+    /// Lectures alternatives à proposer par segment (passes de décodage supplémentaires)
+    pub alternatives: u32,
+}
+
+impl Default for DecodingConfig {
+    fn default() -> Self {
+        Self {
+            strategy: DecodingStrategy::Greedy,
+            best_of: 1,
+            beam_size: 5,
+            temperature: 0.0,
+            temperature_increment: 0.2,
+            deterministic: false,
+            alternatives: 0,
+        }
+    }
+}
+
+impl DecodingConfig {
+    /// Réglages du mode déterministe: greedy, température nulle, sans repli ni alternatives.
+    pub fn deterministic() -> Self {
+        Self {
+            strategy: DecodingStrategy::Greedy,
+            best_of: 1,
+            temperature: 0.0,
+            temperature_increment: 0.0,
+            deterministic: true,
+            alternatives: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Réglages effectifs: ceux du mode déterministe s'il est activé.
+    pub fn effective(&self) -> Self {
+        if self.deterministic {
+            Self::deterministic()
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Vérifie la cohérence des valeurs.
+    ///
+    /// # Errors
+    /// Returns `ConfigurationError` for out-of-range values.
+    pub fn validate(&self) -> Result<(), AppError> {
+        if self.best_of < 1 || self.beam_size < 1 {
+            return Err(AppError::ConfigurationError(
+                "best_of et beam_size doivent être ≥ 1".to_string(),
+            ));
+        }
+        if !(0.0..=1.0).contains(&self.temperature) || self.temperature_increment < 0.0 {
+            return Err(AppError::ConfigurationError(
+                "temperature doit être entre 0.0 et 1.0, temperature_increment ≥ 0.0".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Section [model] - lifetime of the Whisper model in memory.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ModelConfig {
+    /// Modèle par défaut: nom de fichier dans le dossier des modèles
+    /// (absent = ggml-large-v3.bin, sinon le premier modèle installé)
+    pub name: Option<String>,
+    /// Décharger le modèle après N secondes sans transcription (0 = jamais)
+    pub idle_timeout_secs: u64,
+    /// Charger le modèle en arrière-plan dès le démarrage
+    pub preload: bool,
+}
+
+impl ModelConfig {
+    /// Délai d'inactivité avant déchargement, `None` si désactivé.
+    pub fn idle_timeout(&self) -> Option<Duration> {
+        if self.idle_timeout_secs == 0 {
+            None
+        } else {
+            Some(Duration::from_secs(self.idle_timeout_secs))
+        }
+    }
+}
+
+/// Section [privacy] - where recorded audio lives until it is transcribed.
+///
+/// With `in_memory`, recordings are never written as WAV files: samples go
+/// straight from the microphone to Whisper (NFR-SEC-3).
+/// With `retention_secs`, the last transcribed recording is kept that long
+/// so it can be re-transcribed with other settings, then deleted.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct PrivacyConfig {
+    /// Garder l'audio en mémoire au lieu d'un WAV temporaire sur disque
+    pub in_memory: bool,
+    /// Garder le dernier enregistrement N secondes après sa transcription (0 = jamais)
+    pub retention_secs: u64,
+}
+
+impl PrivacyConfig {
+    /// Fenêtre de rétention du dernier enregistrement, `None` si désactivée.
+    pub fn retention(&self) -> Option<Duration> {
+        if self.retention_secs == 0 {
+            None
+        } else {
+            Some(Duration::from_secs(self.retention_secs))
+        }
+    }
+}
+
+/// Section [note] - the current note kept by the backend.
+///
+/// With `voice_commands` (off by default), a recording whose first sentence
+/// is an editing command ("supprime la dernière phrase", "replace Monday
+/// with Tuesday", "annule") edits the current note instead of replacing it.
+///
+/// With `append`, successive recordings are added to the note, joined by
+/// `separator`, until a new note is started ("nouvelle note", `new_note`
+/// or the CmdOrCtrl+Alt+N hotkey).
+///
+/// ```toml
+/// [note]
+/// voice_commands = true
+/// append = true
+/// separator = "newline"
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct NoteConfig {
+    /// Reconnaître les commandes d'édition vocale (désactivé par défaut)
+    pub voice_commands: bool,
+    /// Ajouter chaque transcription à la note au lieu de la remplacer
+    pub append: bool,
+    /// Séparateur entre deux transcriptions ajoutées
+    pub separator: NoteSeparator,
+}
+
+impl Default for NoteConfig {
+    fn default() -> Self {
+        Self {
+            voice_commands: false,
+            append: false,
+            separator: NoteSeparator::Space,
+        }
+    }
+}
+
+/// Séparateur entre les transcriptions d'une note en mode ajout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NoteSeparator {
+    /// Même paragraphe
+    Space,
+    /// Retour à la ligne
+    Newline,
+    /// Ligne vide entre les deux
+    Paragraph,
+}
+
+impl NoteSeparator {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            NoteSeparator::Space => " ",
+            NoteSeparator::Newline => "\n",
+            NoteSeparator::Paragraph => "\n\n",
+        }
+    }
+}
+
+/// Section [transcription.chunking] - long recordings transcribed window by window.
+///
+/// Recordings longer than `threshold_secs` are split into `window_secs` windows,
+/// cut at the quietest point within `silence_search_secs` of each boundary, with
+/// `overlap_secs` of shared context. A checkpoint after each window allows resuming.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChunkingConfig {
+    /// Durée à partir de laquelle l'audio est découpé
+    pub threshold_secs: u64,
+    /// Durée visée d'une fenêtre
+    pub window_secs: u64,
+    /// Contexte partagé entre deux fenêtres consécutives
+    pub overlap_secs: u64,
+    /// Rayon de recherche du point le plus silencieux autour de chaque coupure
+    pub silence_search_secs: u64,
+}
+
+impl Default for ChunkingConfig {
+    fn default() -> Self {
+        Self {
+            threshold_secs: 30 * 60,
+            window_secs: 5 * 60,
+            overlap_secs: 2,
+            silence_search_secs: 15,
+        }
+    }
+}
+
+impl ChunkingConfig {
+    /// Vérifie qu'une fenêtre avance toujours au-delà du recouvrement et de la recherche.
+    ///
+    /// # Errors
+    /// Returns `ConfigurationError` if windows could not make progress.
+    pub fn validate(&self) -> Result<(), AppError> {
+        if self.window_secs <= self.overlap_secs + 2 * self.silence_search_secs {
+            return Err(AppError::ConfigurationError(
+                "window_secs doit dépasser overlap_secs + 2 × silence_search_secs".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Phrases produites par Whisper sur du silence ou du bruit (génériques de sous-titres).
+const DEFAULT_PHANTOM_PHRASES: &[&str] = &[
+    "Sous-titres réalisés par la communauté d'Amara.org",
+    "Sous-titrage Société Radio-Canada",
+    "Sous-titrage ST' 501",
+    "Merci d'avoir regardé cette vidéo",
+    "Subtitles by the Amara.org community",
+    "Thank you for watching",
+    "Thanks for watching",
+];
+
+/// Section [transcription.filter] - post-decode hallucination filtering.
+///
+/// ```toml
+/// [transcription.filter]
+/// no_speech_threshold = 0.8
+/// phantom_phrases = ["Sous-titres réalisés par la communauté d'Amara.org"]
+/// ```
+///
+/// `phantom_phrases` replaces the built-in list when set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FilterConfig {
+    pub enabled: bool,
+    /// Probabilité d'absence de parole au-delà de laquelle un segment est retiré
+    pub no_speech_threshold: f32,
+    /// Ne garder qu'une occurrence des segments répétés à la suite
+    pub collapse_repeats: bool,
+    /// Phrases fantômes: tout segment qui en contient une est retiré
+    pub phantom_phrases: Vec<String>,
+}
+
+impl Default for FilterConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            no_speech_threshold: 0.8,
+            collapse_repeats: true,
+            phantom_phrases: DEFAULT_PHANTOM_PHRASES
+                .iter()
+                .map(|p| p.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl FilterConfig {
+    /// Vérifie que le seuil est une probabilité.
+    ///
+    /// # Errors
+    /// Returns `ConfigurationError` if `no_speech_threshold` is outside 0.0-1.0.
+    pub fn validate(&self) -> Result<(), AppError> {
+        if !(0.0..=1.0).contains(&self.no_speech_threshold) {
+            return Err(AppError::ConfigurationError(
+                "no_speech_threshold doit être compris entre 0.0 et 1.0".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Mots d'hésitation retirés par défaut, par langue.
+const DEFAULT_FILLERS: &[(&str, &[&str])] = &[
+    ("fr", &["euh", "euhm", "heu", "hum", "ben", "bah"]),
+    ("en", &["um", "uh", "uhm", "erm", "hmm"]),
+];
+
+/// Mots légitimement répétés ("nous nous sommes", "had had"), par langue.
+const DEFAULT_ALLOWED_REPEATS: &[(&str, &[&str])] =
+    &[("fr", &["nous", "vous"]), ("en", &["that", "had"])];
+
+/// Commandes de dictée intégrées (phrase prononcée, texte produit), par langue.
+const DEFAULT_COMMANDS: &[(&str, &[(&str, &str)])] = &[
+    (
+        "fr",
+        &[
+            ("virgule", ","),
+            ("point final", "."),
+            ("point à la ligne", ".\n"),
+            ("point d'interrogation", "?"),
+            ("point d'exclamation", "!"),
+            ("point-virgule", ";"),
+            ("point virgule", ";"),
+            ("deux-points", ":"),
+            ("deux points", ":"),
+            ("points de suspension", "…"),
+            ("à la ligne", "\n"),
+            ("nouvelle ligne", "\n"),
+            ("nouveau paragraphe", "\n\n"),
+            ("ouvrez les guillemets", "«"),
+            ("fermez les guillemets", "»"),
+            ("ouvrir les guillemets", "«"),
+            ("fermer les guillemets", "»"),
+            ("ouvrez la parenthèse", "("),
+            ("fermez la parenthèse", ")"),
+        ],
+    ),
+    (
+        "en",
+        &[
+            ("comma", ","),
+            ("period", "."),
+            ("full stop", "."),
+            ("question mark", "?"),
+            ("exclamation mark", "!"),
+            ("exclamation point", "!"),
+            ("semicolon", ";"),
+            ("colon", ":"),
+            ("ellipsis", "…"),
+            ("new line", "\n"),
+            ("new paragraph", "\n\n"),
+            ("open quote", "“"),
+            ("close quote", "”"),
+            ("open parenthesis", "("),
+            ("close parenthesis", ")"),
+        ],
+    ),
+];
+
+/// Symboles du mode code (nom prononcé, symbole), par langue.
+const DEFAULT_SYMBOLS: &[(&str, &[(&str, &str)])] = &[
+    (
+        "fr",
+        &[
+            ("ouvre parenthèse", "("),
+            ("ferme parenthèse", ")"),
+            ("ouvre crochet", "["),
+            ("ferme crochet", "]"),
+            ("ouvre accolade", "{"),
+            ("ferme accolade", "}"),
+            ("inférieur à", "<"),
+            ("supérieur à", ">"),
+            ("flèche", "->"),
+            ("grosse flèche", "=>"),
+            ("égal", "="),
+            ("double égal", "=="),
+            ("différent de", "!="),
+            ("tiret", "-"),
+            ("tiret bas", "_"),
+            ("étoile", "*"),
+            ("slash", "/"),
+            ("antislash", "\\"),
+            ("point", "."),
+            ("virgule", ","),
+            ("deux points", ":"),
+            ("double deux points", "::"),
+            ("point virgule", ";"),
+            ("barre verticale", "|"),
+            ("esperluette", "&"),
+            ("dièse", "#"),
+            ("arobase", "@"),
+            ("accent grave", "`"),
+            ("guillemet", "\""),
+            ("apostrophe", "'"),
+            ("point d'exclamation", "!"),
+            ("point d'interrogation", "?"),
+        ],
+    ),
+    (
+        "en",
+        &[
+            ("open paren", "("),
+            ("close paren", ")"),
+            ("open bracket", "["),
+            ("close bracket", "]"),
+            ("open brace", "{"),
+            ("close brace", "}"),
+            ("less than", "<"),
+            ("greater than", ">"),
+            ("arrow", "->"),
+            ("fat arrow", "=>"),
+            ("equals", "="),
+            ("double equals", "=="),
+            ("not equals", "!="),
+            ("plus", "+"),
+            ("minus", "-"),
+            ("dash", "-"),
+            ("underscore", "_"),
+            ("star", "*"),
+            ("slash", "/"),
+            ("backslash", "\\"),
+            ("dot", "."),
+            ("comma", ","),
+            ("colon", ":"),
+            ("double colon", "::"),
+            ("semicolon", ";"),
+            ("pipe", "|"),
+            ("ampersand", "&"),
+            ("hash", "#"),
+            ("at sign", "@"),
+            ("dollar sign", "$"),
+            ("backtick", "`"),
+            ("double quote", "\""),
+            ("single quote", "'"),
+            ("bang", "!"),
+            ("question mark", "?"),
+        ],
+    ),
+];
+
+/// Section [postprocess] - text transforms applied after transcription.
+///
+/// Opt-in: without `enabled = true`, Whisper's text is delivered as is.
+///
+/// ```toml
+/// [postprocess]
+/// enabled = true
+/// collapse_duplicates = false
+///
+/// [postprocess.languages.fr]
+/// fillers = ["euh", "ben"]
+///
+/// [postprocess.languages.fr.commands]
+/// "tiret" = "–"
+///
+/// [postprocess.languages.en.symbols]
+/// "thin arrow" = "->"
+/// ```
+///
+/// Per-language lists replace the built-in ones (French and English) when set;
+/// dictation commands and code symbols extend the built-in tables instead. Replacement rules
+/// live in their own file (rules.toml next to config.toml by default).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PostProcessConfig {
+    /// Active le post-traitement (désactivé par défaut)
+    pub enabled: bool,
+    /// Retirer les mots d'hésitation ("euh", "um"...)
+    pub remove_fillers: bool,
+    /// Majuscule en début de phrase
+    pub fix_capitalization: bool,
+    /// Fusionner les mots répétés à la suite ("le le chat")
+    pub collapse_duplicates: bool,
+    /// Espaces multiples, espaces avant virgule/point, espaces en bord de ligne
+    pub normalize_whitespace: bool,
+    /// Interpréter les commandes de dictée ("virgule", "à la ligne"...)
+    pub dictation_commands: bool,
+    /// Écrire les nombres, dates et heures en chiffres ("vingt-trois" → "23")
+    pub normalize_numbers: bool,
+    /// Typographie française: espaces fines insécables, guillemets, apostrophes
+    pub french_typography: bool,
+    /// Mode code: casse des identifiants et symboles prononcés ("camel case user id");
+    /// les majuscules de début de phrase ne sont plus ajoutées
+    pub code_dictation: bool,
+    /// Appliquer les règles de remplacement du fichier de règles
+    pub apply_rules: bool,
+    /// Fichier de règles (sinon rules.toml à côté de config.toml)
+    pub rules_file: Option<PathBuf>,
+    /// Règles par code langue ISO 639-1
+    pub languages: HashMap<String, LanguageRulesConfig>,
+}
+
+impl Default for PostProcessConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            remove_fillers: true,
+            fix_capitalization: true,
+            collapse_duplicates: true,
+            normalize_whitespace: true,
+            dictation_commands: false,
+            normalize_numbers: false,
+            french_typography: false,
+            code_dictation: false,
+            apply_rules: true,
+            rules_file: None,
+            languages: HashMap::new(),
+        }
+    }
+}
+
+/// Section [postprocess.languages.<code>] - word lists for one language.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LanguageRulesConfig {
+    /// Mots d'hésitation à retirer
+    pub fillers: Option<Vec<String>>,
+    /// Mots dont la répétition est conservée
+    pub allowed_repeats: Option<Vec<String>>,
+    /// Commandes de dictée ajoutées ou redéfinies (phrase → texte produit)
+    pub commands: HashMap<String, String>,
+    /// Symboles du mode code ajoutés ou redéfinis (nom prononcé → symbole)
+    pub symbols: HashMap<String, String>,
+}
+
+/// Règles effectives d'une langue (configuration, sinon valeurs intégrées).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LanguageRules {
+    pub fillers: Vec<String>,
+    pub allowed_repeats: Vec<String>,
+    /// Commandes de dictée (phrase en minuscules, texte produit)
+    pub commands: Vec<(String, String)>,
+    /// Symboles du mode code (nom en minuscules, symbole)
+    pub symbols: Vec<(String, String)>,
+}
+
+impl PostProcessConfig {
+    /// Règles de `language`; listes vides pour une langue inconnue ou absente.
+    pub fn rules(&self, language: Option<&str>) -> LanguageRules {
+        let configured = language.and_then(|code| self.languages.get(code));
+        LanguageRules {
+            fillers: configured
+                .and_then(|c| c.fillers.clone())
+                .unwrap_or_else(|| builtin_words(DEFAULT_FILLERS, language)),
+            allowed_repeats: configured
+                .and_then(|c| c.allowed_repeats.clone())
+                .unwrap_or_else(|| builtin_words(DEFAULT_ALLOWED_REPEATS, language)),
+            commands: merged_phrases(DEFAULT_COMMANDS, language, configured.map(|c| &c.commands)),
+            symbols: merged_phrases(DEFAULT_SYMBOLS, language, configured.map(|c| &c.symbols)),
+        }
+    }
+
+    /// Applique les surcharges d'un profil.
+    pub fn with_overrides(&self, overrides: &PostProcessOverrides) -> Self {
+        Self {
+            enabled: overrides.enabled.unwrap_or(self.enabled),
+            remove_fillers: overrides.remove_fillers.unwrap_or(self.remove_fillers),
+            fix_capitalization: overrides
+                .fix_capitalization
+                .unwrap_or(self.fix_capitalization),
+            collapse_duplicates: overrides
+                .collapse_duplicates
+                .unwrap_or(self.collapse_duplicates),
+            normalize_whitespace: overrides
+                .normalize_whitespace
+                .unwrap_or(self.normalize_whitespace),
+            dictation_commands: overrides
+                .dictation_commands
+                .unwrap_or(self.dictation_commands),
+            normalize_numbers: overrides
+                .normalize_numbers
+                .unwrap_or(self.normalize_numbers),
+            french_typography: overrides
+                .french_typography
+                .unwrap_or(self.french_typography),
+            code_dictation: overrides.code_dictation.unwrap_or(self.code_dictation),
+            apply_rules: overrides.apply_rules.unwrap_or(self.apply_rules),
+            rules_file: self.rules_file.clone(),
+            languages: self.languages.clone(),
+        }
+    }
+}
+
+/// Liste intégrée d'une langue, vide si elle n'en a pas.
+fn builtin_words(table: &[(&str, &[&str])], language: Option<&str>) -> Vec<String> {
+    table
+        .iter()
+        .find(|(code, _)| Some(*code) == language)
+        .map(|(_, words)| words.iter().map(|w| w.to_string()).collect())
+        .unwrap_or_default()
+}
+
+/// Table intégrée d'une langue (commandes, symboles), complétée par la configuration.
+fn merged_phrases(
+    table: &[(&str, &[(&str, &str)])],
+    language: Option<&str>,
+    configured: Option<&HashMap<String, String>>,
+) -> Vec<(String, String)> {
+    let mut phrases: Vec<(String, String)> = table
+        .iter()
+        .filter(|(code, _)| Some(*code) == language)
+        .flat_map(|(_, table)| table.iter())
+        .map(|(phrase, output)| (phrase.to_string(), output.to_string()))
+        .collect();
+
+    for (phrase, output) in configured.into_iter().flatten() {
+        let phrase = phrase.to_lowercase();
+        match phrases.iter_mut().find(|(p, _)| *p == phrase) {
+            Some(entry) => entry.1 = output.clone(),
+            None => phrases.push((phrase, output.clone())),
+        }
+    }
+    phrases
+}
+
+/// Section [profiles.<name>.postprocess] - every field overrides [postprocess] when set.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct PostProcessOverrides {
+    pub enabled: Option<bool>,
+    pub remove_fillers: Option<bool>,
+    pub fix_capitalization: Option<bool>,
+    pub collapse_duplicates: Option<bool>,
+    pub normalize_whitespace: Option<bool>,
+    pub dictation_commands: Option<bool>,
+    pub normalize_numbers: Option<bool>,
+    pub french_typography: Option<bool>,
+    pub code_dictation: Option<bool>,
+    pub apply_rules: Option<bool>,
+}
+
+/// Section [glossary] - product names, people and jargon Whisper should spell right.
+///
+/// Terms come from `terms`, `groups` and the glossary file (one term per line,
+/// `[group]` headers). Without `file`, glossary.txt next to config.toml is used if present.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct GlossaryConfig {
+    /// Fichier glossaire (sinon glossary.txt à côté de config.toml)
+    pub file: Option<PathBuf>,
+    /// Termes toujours inclus
+    pub terms: Vec<String>,
+    /// Groupes de termes sélectionnables par profil
+    pub groups: HashMap<String, Vec<String>>,
+}
+
+/// Section [profiles.<name>] - every field overrides [transcription] when set.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ProfileConfig {
+    pub language: Option<String>,
+    pub translate: Option<bool>,
+    /// Marquer les tours de parole (entretiens à deux)
+    pub diarize: Option<bool>,
+    /// Texte libre placé en tête du prompt initial (contexte, style)
+    pub initial_prompt: Option<String>,
+    /// Groupes du glossaire à utiliser (tous si absent)
+    pub glossary_groups: Option<Vec<String>>,
+    /// Étapes de post-traitement activées ou désactivées pour ce profil
+    pub postprocess: PostProcessOverrides,
+}
+
+impl AppConfig {
+    /// Returns the named profile.
+    ///
+    /// # Errors
+    /// Returns `ConfigurationError` if no profile with this name exists.
+    pub fn profile(&self, name: &str) -> Result<&ProfileConfig, AppError> {
+        self.profiles.get(name).ok_or_else(|| {
+            AppError::ConfigurationError(format!("Profil inconnu: '{}'", name))
+        })
+    }
+}
+
+/// Returns the configuration directory, which also holds the user's
+/// glossary.txt and rules.toml.
+///
+/// Platform paths:
+/// - Linux: ~/.config/vocal-note-taker/
+/// - macOS: ~/Library/Application Support/vocal-note-taker/
+///
+/// # Errors
+/// Returns `ConfigurationError` if the system config directory cannot be determined.
+pub fn get_config_dir() -> Result<PathBuf, AppError> {
+    let mut path = dirs::config_dir().ok_or_else(|| {
+        AppError::ConfigurationError(
+            "Impossible de déterminer le répertoire de configuration".to_string(),
+        )
+    })?;
+    path.push("vocal-note-taker");
+    Ok(path)
+}
+
+/// Returns the path of the configuration file (config.toml in `get_config_dir`).
+///
+/// # Errors
+/// Returns `ConfigurationError` if the system config directory cannot be determined.
+pub fn get_config_path() -> Result<PathBuf, AppError> {
+    Ok(get_config_dir()?.join("config.toml"))
+}
+
+/// Charge la configuration depuis le fichier TOML.
+///
+/// # Errors
+/// Retourne `AppError::ConfigurationError` si fichier illisible ou TOML invalide.
+pub fn load_config(path: &Path) -> Result<AppConfig, AppError> {
+    let content = fs::read_to_string(path).map_err(|e| {
+        AppError::ConfigurationError(format!("Lecture impossible de {}: {}", path.display(), e))
+    })?;
+    parse_config(&content)
+}
+
+/// Parse le contenu TOML de la configuration.
+///
+/// # Errors
+/// Retourne `AppError::ConfigurationError` si le TOML est invalide.
+pub fn parse_config(content: &str) -> Result<AppConfig, AppError> {
+    let config: AppConfig = toml::from_str(content)
+        .map_err(|e| AppError::ConfigurationError(format!("TOML invalide: {}", e)))?;
+
+    if let Some(ref name) = config.active_profile {
+        config.profile(name)?;
+    }
+    if config.transcription.n_threads == Some(0) {
+        return Err(AppError::ConfigurationError(
+            "n_threads doit être ≥ 1 (ou absent pour le choix automatique)".to_string(),
+        ));
+    }
+    config.transcription.decoding.validate()?;
+    config.transcription.chunking.validate()?;
+    config.transcription.filter.validate()?;
+
+    Ok(config)
+}
+
+/// Loads the configuration at startup.
+///
+/// Non-fatal: falls back to defaults if the file is missing or invalid,
+/// so the app stays usable with a broken config.toml.
+pub fn load_config_or_default() -> AppConfig {
+    let path = match get_config_path() {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!("Warning: {}", e);
+            return AppConfig::default();
+        }
+    };
+
+    if !path.exists() {
+        println!("No config file at {}, using defaults", path.display());
+        return AppConfig::default();
+    }
+
+    match load_config(&path) {
+        Ok(config) => {
+            println!("Configuration loaded from: {}", path.display());
+            config
+        }
+        Err(e) => {
+            eprintln!("Warning: {}", e);
+            eprintln!("Using default configuration.");
+            AppConfig::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config() {
+        let config = AppConfig::default();
+        assert_eq!(config.transcription.language, "auto");
+        assert!(!config.transcription.translate);
+        assert!(!config.transcription.diarize);
+        assert!(config.active_profile.is_none());
+        assert!(config.profiles.is_empty());
+    }
+
+    #[test]
+    fn test_parse_empty_config_uses_defaults() {
+        let config = parse_config("").unwrap();
+        assert_eq!(config.transcription.language, "auto");
+    }
+
+    #[test]
+    fn test_parse_config_with_profiles() {
+        let config = parse_config(
+            r#"
+            active_profile = "interview"
+
+            [transcription]
+            language = "fr"
+
+            [profiles.interview]
+            language = "en"
+            translate = true
+            diarize = true
+
+            [profiles.notes]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.transcription.language, "fr");
+        assert_eq!(config.active_profile.as_deref(), Some("interview"));
+        assert_eq!(
+            config.profile("interview").unwrap().language.as_deref(),
+            Some("en")
+        );
+        assert_eq!(config.profile("interview").unwrap().translate, Some(true));
+        assert_eq!(config.profile("interview").unwrap().diarize, Some(true));
+        assert!(config.profile("notes").unwrap().language.is_none());
+        assert!(config.profile("notes").unwrap().translate.is_none());
+    }
+
+    #[test]
+    fn test_parse_n_threads() {
+        assert_eq!(AppConfig::default().transcription.n_threads, None);
+
+        let config = parse_config("[transcription]\nn_threads = 8").unwrap();
+        assert_eq!(config.transcription.n_threads, Some(8));
+
+        let result = parse_config("[transcription]\nn_threads = 0");
+        assert!(matches!(result, Err(AppError::ConfigurationError(_))));
+    }
+
+    #[test]
+    fn test_parse_postprocess_section() {
+        let defaults = AppConfig::default().postprocess;
+        assert!(!defaults.enabled, "Post-processing is opt-in");
+        assert!(defaults.remove_fillers);
+        assert!(defaults
+            .rules(Some("fr"))
+            .fillers
+            .contains(&"euh".to_string()));
+        assert!(defaults
+            .rules(Some("en"))
+            .fillers
+            .contains(&"um".to_string()));
+        assert_eq!(defaults.rules(None), LanguageRules::default());
+
+        let config = parse_config(
+            r#"
+            [postprocess]
+            collapse_duplicates = false
+
+            [postprocess.languages.fr]
+            fillers = ["euh"]
+
+            [profiles.dictee.postprocess]
+            remove_fillers = false
+            "#,
+        )
+        .unwrap();
+        let rules = config.postprocess.rules(Some("fr"));
+        assert_eq!(rules.fillers, vec!["euh"]);
+        assert_eq!(
+            rules.allowed_repeats,
+            vec!["nous", "vous"],
+            "Built-in list kept"
+        );
+
+        let overridden = config
+            .postprocess
+            .with_overrides(&config.profile("dictee").unwrap().postprocess);
+        assert!(!overridden.remove_fillers);
+        assert!(!overridden.collapse_duplicates);
+        assert!(overridden.fix_capitalization);
+    }
+
+    #[test]
+    fn test_dictation_commands_extend_builtin_table() {
+        assert!(!AppConfig::default().postprocess.dictation_commands);
+
+        let config = parse_config(
+            r#"
+            [postprocess.languages.fr.commands]
+            "Tiret" = "–"
+            "virgule" = " ,"
+            "#,
+        )
+        .unwrap();
+        let commands = config.postprocess.rules(Some("fr")).commands;
+        let output = |phrase: &str| {
+            commands
+                .iter()
+                .find(|(p, _)| p == phrase)
+                .map(|(_, o)| o.as_str())
+        };
+
+        assert_eq!(output("tiret"), Some("–"));
+        assert_eq!(output("virgule"), Some(" ,"), "Redefined");
+        assert_eq!(output("nouveau paragraphe"), Some("\n\n"), "Built-in kept");
+        assert!(config.postprocess.rules(Some("de")).commands.is_empty());
+    }
+
+    #[test]
+    fn test_parse_privacy_section() {
+        assert!(!AppConfig::default().privacy.in_memory);
+
+        assert!(AppConfig::default().privacy.retention().is_none());
+
+        let config = parse_config("[privacy]\nin_memory = true\nretention_secs = 120").unwrap();
+        assert!(config.privacy.in_memory);
+        assert_eq!(config.privacy.retention(), Some(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn test_parse_note_section() {
+        assert!(!AppConfig::default().note.voice_commands);
+
+        assert!(!AppConfig::default().note.append);
+
+        let config = parse_config(
+            r#"
+            [note]
+            voice_commands = true
+            append = true
+            separator = "paragraph"
+            "#,
+        )
+        .unwrap();
+        assert!(config.note.voice_commands);
+        assert!(config.note.append);
+        assert_eq!(config.note.separator.as_str(), "\n\n");
+
+        assert!(parse_config("[note]\nseparator = \"tab\"").is_err());
+    }
+
+    #[test]
+    fn test_parse_filter_section() {
+        let defaults = AppConfig::default().transcription.filter;
+        assert!(defaults.enabled);
+        assert!(defaults
+            .phantom_phrases
+            .iter()
+            .any(|p| p.contains("Amara.org")));
+
+        let config = parse_config(
+            r#"
+            [transcription.filter]
+            no_speech_threshold = 0.5
+            phantom_phrases = ["Abonnez-vous"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.transcription.filter.no_speech_threshold, 0.5);
+        assert_eq!(
+            config.transcription.filter.phantom_phrases,
+            vec!["Abonnez-vous"]
+        );
+        assert!(config.transcription.filter.collapse_repeats);
+
+        let result = parse_config("[transcription.filter]\nno_speech_threshold = 1.5");
+        assert!(matches!(result, Err(AppError::ConfigurationError(_))));
+    }
+
+    #[test]
+    fn test_parse_chunking_section() {
+        let config = parse_config(
+            r#"
+            [transcription.chunking]
+            threshold_secs = 600
+            window_secs = 120
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.transcription.chunking.threshold_secs, 600);
+        assert_eq!(config.transcription.chunking.window_secs, 120);
+        assert_eq!(config.transcription.chunking.overlap_secs, 2);
+
+        let result = parse_config("[transcription.chunking]\nwindow_secs = 20");
+        assert!(matches!(result, Err(AppError::ConfigurationError(_))));
+    }
+
+    #[test]
+    fn test_parse_model_idle_timeout() {
+        assert_eq!(AppConfig::default().model.idle_timeout(), None);
+        assert!(!AppConfig::default().model.preload);
+
+        let config = parse_config(
+            "[model]\nname = \"ggml-medium.bin\"\npreload = true\nidle_timeout_secs = 300",
+        )
+        .unwrap();
+        assert_eq!(config.model.name.as_deref(), Some("ggml-medium.bin"));
+        assert!(config.model.preload);
+        assert_eq!(config.model.idle_timeout(), Some(Duration::from_secs(300)));
+    }
+
+    #[test]
+    fn test_parse_glossary_section() {
+        let config = parse_config(
+            r#"
+            [glossary]
+            terms = ["Tamles"]
+
+            [glossary.groups]
+            equipe = ["Amélie", "Joachim"]
+
+            [profiles.reunion]
+            glossary_groups = ["equipe"]
+            initial_prompt = "Réunion d'équipe."
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.glossary.terms, vec!["Tamles"]);
+        assert_eq!(config.glossary.groups["equipe"].len(), 2);
+        assert!(config.glossary.file.is_none());
+        let profile = config.profile("reunion").unwrap();
+        assert_eq!(
+            profile.glossary_groups.as_deref(),
+            Some(&["equipe".to_string()][..])
+        );
+        assert_eq!(profile.initial_prompt.as_deref(), Some("Réunion d'équipe."));
+    }
+
+    #[test]
+    fn test_parse_decoding_section() {
+        let config = parse_config(
+            r#"
+            [transcription.decoding]
+            strategy = "beam_search"
+            beam_size = 8
+            alternatives = 2
+            "#,
+        )
+        .unwrap();
+
+        let decoding = &config.transcription.decoding;
+        assert_eq!(decoding.strategy, DecodingStrategy::BeamSearch);
+        assert_eq!(decoding.beam_size, 8);
+        assert_eq!(decoding.alternatives, 2);
+        assert_eq!(decoding.temperature_increment, 0.2);
+    }
+
+    #[test]
+    fn test_deterministic_decoding_overrides_settings() {
+        let decoding = DecodingConfig {
+            strategy: DecodingStrategy::BeamSearch,
+            temperature: 0.5,
+            alternatives: 3,
+            deterministic: true,
+            ..Default::default()
+        };
+
+        let effective = decoding.effective();
+        assert_eq!(effective.strategy, DecodingStrategy::Greedy);
+        assert_eq!(effective.best_of, 1);
+        assert_eq!(effective.temperature, 0.0);
+        assert_eq!(effective.temperature_increment, 0.0);
+        assert_eq!(effective.alternatives, 0);
+    }
+
+    #[test]
+    fn test_invalid_decoding_is_rejected() {
+        let result = parse_config("[transcription.decoding]\nbeam_size = 0");
+        assert!(matches!(result, Err(AppError::ConfigurationError(_))));
+
+        let result = parse_config("[transcription.decoding]\ntemperature = 1.5");
+        assert!(matches!(result, Err(AppError::ConfigurationError(_))));
+
+        let result = parse_config(
+            r#"[transcription.decoding]
+            strategy = "sampling""#,
+        );
+        assert!(matches!(result, Err(AppError::ConfigurationError(_))));
+    }
+
+    #[test]
+    fn test_parse_config_unknown_active_profile() {
+        let result = parse_config(r#"active_profile = "missing""#);
+        match result {
+            Err(AppError::ConfigurationError(msg)) => assert!(msg.contains("missing")),
+            other => panic!("Expected ConfigurationError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid_toml() {
+        let result = parse_config("[transcription\nlanguage = ");
+        assert!(matches!(result, Err(AppError::ConfigurationError(_))));
+    }
+
+    #[test]
+    fn test_load_config_missing_file() {
+        let result = load_config(Path::new("/nonexistent/config.toml"));
+        assert!(matches!(result, Err(AppError::ConfigurationError(_))));
+    }
+
+    #[test]
+    fn test_get_config_path() {
+        let path = get_config_path().unwrap();
+        assert!(path.to_string_lossy().contains("vocal-note-taker"));
+        assert!(path.ends_with("config.toml"));
+    }
+}
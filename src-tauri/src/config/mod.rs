@@ -4,3 +4,6 @@
 //! - loader: configuration file loading and parsing
 
 pub mod loader;
+
+// Re-exports for convenient access
+pub use loader::{load_config_or_default, AppConfig};
@@ -41,22 +41,18 @@ pub enum AppError {
 
     #[error("Format audio invalide: {0}. Réenregistrez.")]
     InvalidAudioFormat(String),
-}
 
-/// Serialization format for frontend consumption.
-#[derive(Serialize)]
-struct SerializedAppError<'a> {
-    #[serde(rename = "type")]
-    error_type: &'a str,
-    message: String,
+    #[error("Import du modèle échoué: {0}. Vérifiez le fichier source et l'espace disque disponible.")]
+    ModelImportFailed(String),
+
+    #[error("Commande vocale non appliquée: {0}")]
+    NoteEditFailed(String),
 }
 
-impl Serialize for AppError {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let error_type = match self {
+impl AppError {
+    /// Nom de la variante, envoyé au frontend comme champ `type`.
+    pub fn kind(&self) -> &'static str {
+        match self {
             AppError::MicrophoneAccessDenied => "MicrophoneAccessDenied",
             AppError::MicrophoneNotFound => "MicrophoneNotFound",
             AppError::TranscriptionFailed(_) => "TranscriptionFailed",
@@ -68,10 +64,27 @@ impl Serialize for AppError {
             AppError::ModelNotFound(_) => "ModelNotFound",
             AppError::ModelLoadFailed(_) => "ModelLoadFailed",
             AppError::InvalidAudioFormat(_) => "InvalidAudioFormat",
-        };
+            AppError::ModelImportFailed(_) => "ModelImportFailed",
+            AppError::NoteEditFailed(_) => "NoteEditFailed",
+        }
+    }
+}
+
+/// Serialization format for frontend consumption.
+#[derive(Serialize)]
+struct SerializedAppError<'a> {
+    #[serde(rename = "type")]
+    error_type: &'a str,
+    message: String,
+}
 
+impl Serialize for AppError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
         SerializedAppError {
-            error_type,
+            error_type: self.kind(),
             message: self.to_string(),
         }
         .serialize(serializer)
@@ -185,6 +198,8 @@ mod tests {
             AppError::ModelNotFound("test".to_string()),
             AppError::ModelLoadFailed("test".to_string()),
             AppError::InvalidAudioFormat("test".to_string()),
+            AppError::ModelImportFailed("test".to_string()),
+            AppError::NoteEditFailed("test. Réessayez après une dictée.".to_string()),
         ];
 
         for err in errors {
@@ -220,4 +235,14 @@ mod tests {
         assert!(json.contains("bad format"));
         assert!(json.contains("Réenregistrez"));
     }
+
+    #[test]
+    fn test_model_import_failed_serialization() {
+        let err = AppError::ModelImportFailed("en-tête GGML absent".to_string());
+        let json = serde_json::to_string(&err).unwrap();
+
+        assert!(json.contains(r#""type":"ModelImportFailed""#));
+        assert!(json.contains("en-tête GGML absent"));
+        assert!(json.contains("Vérifiez"));
+    }
 }
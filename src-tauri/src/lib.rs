@@ -3,15 +3,18 @@ mod audio;
 mod commands;
 mod config;
 mod error;
+mod note;
 mod system;
+mod text;
 mod transcription;
 
 use tauri::menu::{Menu, MenuItem};
 use tauri::Manager;
 
 use crate::commands::AudioState;
+use crate::note::NoteState;
 use crate::system::hotkeys;
-use crate::transcription::WhisperState;
+use crate::transcription::{JobQueueState, RetentionState, UserFiles, WhisperState};
 
 // Re-exports for external use
 pub use error::AppError;
@@ -35,25 +38,47 @@ fn test_error(error_type: String) -> Result<String, AppError> {
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    let config = crate::config::load_config_or_default();
+    // Glossaire lu une seule fois: un fichier illisible est ignoré avec un avertissement
+    let config_dir = crate::config::loader::get_config_dir().ok();
+    let user_files = UserFiles::load(&config, config_dir.as_deref());
+
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_global_shortcut::Builder::new().build())
         .plugin(tauri_plugin_clipboard_manager::init())
+        .manage(config)
+        .manage(user_files)
         .manage(AudioState::default())
         .manage(WhisperState::default())
+        .manage(JobQueueState::default())
+        .manage(RetentionState::default())
+        .manage(NoteState::default())
         .invoke_handler(tauri::generate_handler![
             test_error,
             commands::get_version,
             commands::request_quit,
             commands::start_recording,
             commands::stop_recording,
+            commands::discard_recording,
             commands::start_transcription,
-            commands::copy_to_clipboard
+            commands::list_jobs,
+            commands::cancel_job,
+            commands::discard_resumable_job,
+            commands::retranscribe,
+            commands::accept_transcription,
+            commands::preview_rules,
+            commands::get_note,
+            commands::undo_note,
+            commands::new_note,
+            commands::copy_to_clipboard,
+            commands::import_model,
+            commands::benchmark_model
         ])
         .setup(|app| {
             // NFR-SEC-3: Cleanup orphaned temp files at startup (crash recovery)
             // Non-fatal: app continues if cleanup fails
-            match crate::system::shutdown::cleanup_temp_files() {
+            match crate::system::shutdown::cleanup_temp_files_at_startup() {
                 Ok(()) => println!("Startup cleanup completed"),
                 Err(e) => eprintln!("Warning: Startup cleanup failed: {:?}", e),
             }
@@ -76,10 +101,14 @@ pub fn run() {
                 eprintln!("Warning: Could not register global shortcuts: {:?}", e);
                 eprintln!("Recording via button still available.");
             }
+            if let Err(e) = hotkeys::register_new_note_shortcut(&app.handle()) {
+                eprintln!("Warning: Could not register new note shortcut: {:?}", e);
+            }
 
             // Check Whisper model availability (Story 3.1)
             // Non-fatal: app continues without model, transcription unavailable until installed
-            match crate::transcription::check_model_availability() {
+            let model_name = app.state::<crate::config::AppConfig>().model.name.clone();
+            match crate::transcription::check_model_availability(model_name.as_deref()) {
                 Ok(path) => {
                     println!("Whisper model found at: {}", path.display());
                 }
@@ -89,6 +118,24 @@ pub fn run() {
                 }
             }
 
+            // Worker de la file de transcription: une tâche à la fois, dans l'ordre
+            let engine = crate::transcription::WhisperEngine::new(
+                app.state::<WhisperState>().model.clone(),
+            );
+            commands::spawn_job_worker(app.handle().clone(), std::sync::Arc::new(engine));
+            commands::resume_interrupted_jobs(app.handle());
+
+            // Précharger le modèle en arrière-plan ([model] preload)
+            if app.state::<crate::config::AppConfig>().model.preload {
+                commands::spawn_model_preload(app.handle().clone());
+            }
+
+            // Libérer la mémoire du modèle après inactivité ([model] idle_timeout_secs)
+            let idle_timeout = app.state::<crate::config::AppConfig>().model.idle_timeout();
+            if let Some(timeout) = idle_timeout {
+                commands::spawn_idle_unloader(app.handle().clone(), timeout);
+            }
+
             Ok(())
         })
         .on_menu_event(|app, event| {
@@ -99,6 +146,9 @@ pub fn run() {
                 // Unregister global shortcuts before exit
                 hotkeys::unregister_all(&app_handle);
 
+                // Enregistrements en mémoire jamais transcrits ([privacy] in_memory)
+                app_handle.state::<AudioState>().memory.clear();
+
                 // Perform graceful shutdown asynchronously
                 tauri::async_runtime::spawn(async move {
                     if let Err(e) = crate::system::shutdown::graceful_shutdown() {
@@ -120,6 +170,9 @@ pub fn run() {
                 // Unregister global shortcuts before exit
                 hotkeys::unregister_all(&app);
 
+                // Enregistrements en mémoire jamais transcrits ([privacy] in_memory)
+                app.state::<AudioState>().memory.clear();
+
                 // Perform graceful shutdown asynchronously
                 tauri::async_runtime::spawn(async move {
                     if let Err(e) = crate::system::shutdown::graceful_shutdown() {
@@ -0,0 +1,330 @@
+//! Edit module - voice editing commands
+//!
+//! A recording whose first sentence is an editing command changes the
+//! current note instead of replacing it: delete the last sentence or word,
+//! replace a word, undo the last change, start a new note. Commands are
+//! recognized in French and English whatever the transcript language; what
+//! follows the command in the same recording is dictation, added after the
+//! edit.
+
+use serde::Serialize;
+
+use crate::error::AppError;
+use crate::text::dictation::normalize;
+use crate::text::rules::{CompiledRule, ReplacementRule};
+
+/// Ponctuation de fin de phrase
+const SENTENCE_END: &[char] = &['.', '!', '?', '…'];
+
+/// Verbes de suppression ("supprime la dernière phrase", "delete last word")
+const DELETE_VERBS: &[&str] = &[
+    "supprime",
+    "supprimer",
+    "efface",
+    "effacer",
+    "delete",
+    "remove",
+    "erase",
+];
+
+const LAST_SENTENCE: &[&[&str]] = &[
+    &["la", "dernière", "phrase"],
+    &["last", "sentence"],
+    &["the", "last", "sentence"],
+];
+
+const LAST_WORD: &[&[&str]] = &[
+    &["le", "dernier", "mot"],
+    &["last", "word"],
+    &["the", "last", "word"],
+];
+
+const UNDO: &[&[&str]] = &[
+    &["annule"],
+    &["annuler"],
+    &["annule", "ça"],
+    &["undo"],
+    &["undo", "that"],
+];
+
+const NEW_NOTE: &[&[&str]] = &[
+    &["nouvelle", "note"],
+    &["new", "note"],
+    &["start", "a", "new", "note"],
+];
+
+/// Verbe de remplacement et mot qui sépare l'ancien texte du nouveau
+const REPLACE: &[(&str, &str)] = &[
+    ("remplace", "par"),
+    ("remplacer", "par"),
+    ("replace", "with"),
+    ("replace", "by"),
+];
+
+/// Commande d'édition de la note courante.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EditCommand {
+    DeleteLastSentence,
+    DeleteLastWord,
+    /// Remplace toutes les occurrences (mots entiers, casse reportée)
+    Replace {
+        find: String,
+        replace: String,
+    },
+    Undo,
+    /// Vide la note (annulable); la dictée qui suit commence la nouvelle note
+    NewNote,
+}
+
+/// Commande reconnue en début de transcription.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedCommand {
+    pub command: EditCommand,
+    /// Dictée qui suit la commande, vide le plus souvent
+    pub rest: String,
+}
+
+/// Sépare la première phrase du reste du texte.
+fn split_first_sentence(text: &str) -> (&str, &str) {
+    let text = text.trim();
+    for (i, c) in text.char_indices() {
+        let after = &text[i + c.len_utf8()..];
+        let boundary = c == '\n'
+            || (SENTENCE_END.contains(&c)
+                && (after.is_empty() || after.starts_with(char::is_whitespace)));
+        if boundary {
+            return (&text[..i], after.trim());
+        }
+    }
+    (text, "")
+}
+
+fn is_phrase(words: &[String], phrase: &[&str]) -> bool {
+    words.len() == phrase.len() && words.iter().zip(phrase).all(|(w, p)| w == p)
+}
+
+/// Texte cité dans une commande, sans la ponctuation ni les guillemets autour.
+fn quoted(tokens: &[&str]) -> String {
+    tokens
+        .join(" ")
+        .trim_matches(|c: char| !c.is_alphanumeric())
+        .to_string()
+}
+
+/// Reconnaît une commande d'édition dans la première phrase d'une transcription.
+///
+/// `None` si la transcription est une dictée ordinaire.
+pub fn parse_command(text: &str) -> Option<ParsedCommand> {
+    let (sentence, rest) = split_first_sentence(text);
+    let tokens: Vec<&str> = sentence.split_whitespace().collect();
+    let words: Vec<String> = tokens.iter().map(|t| normalize(t)).collect();
+    let (verb, object) = words.split_first()?;
+
+    let command = if UNDO.iter().any(|phrase| is_phrase(&words, phrase)) {
+        EditCommand::Undo
+    } else if NEW_NOTE.iter().any(|phrase| is_phrase(&words, phrase)) {
+        EditCommand::NewNote
+    } else if is_phrase(&words, &["scratch", "that"])
+        || (DELETE_VERBS.contains(&verb.as_str())
+            && LAST_SENTENCE.iter().any(|phrase| is_phrase(object, phrase)))
+    {
+        EditCommand::DeleteLastSentence
+    } else if DELETE_VERBS.contains(&verb.as_str())
+        && LAST_WORD.iter().any(|phrase| is_phrase(object, phrase))
+    {
+        EditCommand::DeleteLastWord
+    } else {
+        // "remplace mardi par mercredi": au moins un mot de chaque côté
+        REPLACE
+            .iter()
+            .filter(|(replace_verb, _)| verb == replace_verb)
+            .find_map(|(_, separator)| {
+                let at = (2..words.len().saturating_sub(1)).find(|&k| words[k] == *separator)?;
+                let find = quoted(&tokens[1..at]);
+                let replace = quoted(&tokens[at + 1..]);
+                (!find.is_empty() && !replace.is_empty())
+                    .then_some(EditCommand::Replace { find, replace })
+            })?
+    };
+
+    Some(ParsedCommand {
+        command,
+        rest: rest.to_string(),
+    })
+}
+
+/// Supprime la dernière phrase (ou le dernier paragraphe sans ponctuation).
+fn delete_last_sentence(text: &str) -> String {
+    let body = text.trim_end().trim_end_matches(SENTENCE_END);
+    let cut = body
+        .char_indices()
+        .rev()
+        .find(|&(i, c)| {
+            c == '\n'
+                || (SENTENCE_END.contains(&c)
+                    && body[i + c.len_utf8()..].starts_with(char::is_whitespace))
+        })
+        .map_or(0, |(i, c)| i + c.len_utf8());
+    body[..cut].trim_end().to_string()
+}
+
+/// Supprime le dernier mot; la ponctuation finale reste ("Je pars demain." → "Je pars.").
+fn delete_last_word(text: &str) -> String {
+    let trimmed = text.trim_end();
+    let body = trimmed.trim_end_matches(SENTENCE_END);
+    let end_punctuation = &trimmed[body.len()..];
+    let start = body.rfind(char::is_whitespace).map_or(0, |i| {
+        i + body[i..].chars().next().map_or(1, char::len_utf8)
+    });
+    let kept = body[..start].trim_end().trim_end_matches([',', ';', ':']);
+
+    if kept.is_empty() {
+        String::new()
+    } else {
+        format!("{}{}", kept, end_punctuation)
+    }
+}
+
+impl EditCommand {
+    /// Applique la commande au texte de la note.
+    ///
+    /// `Undo` ne change pas le texte: l'historique est tenu par `NoteState`.
+    ///
+    /// # Errors
+    /// - `NoteEditFailed` si la note est vide ou si le texte à remplacer est introuvable
+    pub fn apply(&self, text: &str) -> Result<String, AppError> {
+        let edits_text = !matches!(self, EditCommand::Undo | EditCommand::NewNote);
+        if text.trim().is_empty() && edits_text {
+            return Err(AppError::NoteEditFailed(
+                "la note est vide. Réessayez après une dictée.".to_string(),
+            ));
+        }
+
+        match self {
+            EditCommand::DeleteLastSentence => Ok(delete_last_sentence(text)),
+            EditCommand::DeleteLastWord => Ok(delete_last_word(text)),
+            EditCommand::Replace { find, replace } => {
+                let rule = CompiledRule::new(ReplacementRule {
+                    find: find.clone(),
+                    replace: replace.clone(),
+                    regex: false,
+                    whole_word: true,
+                    preserve_case: true,
+                })?;
+                match rule.apply(text) {
+                    (_, 0) => Err(AppError::NoteEditFailed(format!(
+                        "« {} » introuvable dans la note. Réessayez en citant le texte de la note.",
+                        find
+                    ))),
+                    (replaced, _) => Ok(replaced),
+                }
+            }
+            EditCommand::Undo => Ok(text.to_string()),
+            EditCommand::NewNote => Ok(String::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(text: &str) -> Option<EditCommand> {
+        parse_command(text).map(|parsed| parsed.command)
+    }
+
+    #[test]
+    fn test_parse_french_and_english_commands() {
+        assert_eq!(
+            command("Supprime la dernière phrase."),
+            Some(EditCommand::DeleteLastSentence)
+        );
+        assert_eq!(
+            command("delete the last word"),
+            Some(EditCommand::DeleteLastWord)
+        );
+        assert_eq!(command("Annule."), Some(EditCommand::Undo));
+        assert_eq!(command("Undo that!"), Some(EditCommand::Undo));
+        assert_eq!(command("Nouvelle note."), Some(EditCommand::NewNote));
+        assert_eq!(
+            command("Remplace «mardi» par mercredi."),
+            Some(EditCommand::Replace {
+                find: "mardi".to_string(),
+                replace: "mercredi".to_string()
+            })
+        );
+        assert_eq!(
+            command("Replace John Smith with Jane Doe"),
+            Some(EditCommand::Replace {
+                find: "John Smith".to_string(),
+                replace: "Jane Doe".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_dictation_is_not_a_command() {
+        assert_eq!(
+            command("Supprime la dernière phrase du contrat demain."),
+            None
+        );
+        assert_eq!(command("Il faut annuler la réunion."), None);
+        assert_eq!(command("Remplace par"), None);
+        assert_eq!(command(""), None);
+    }
+
+    #[test]
+    fn test_command_keeps_following_dictation() {
+        let parsed = parse_command("Supprime la dernière phrase. Nous arrivons mardi.").unwrap();
+        assert_eq!(parsed.command, EditCommand::DeleteLastSentence);
+        assert_eq!(parsed.rest, "Nous arrivons mardi.");
+    }
+
+    #[test]
+    fn test_delete_last_sentence_and_word() {
+        let note = "Bonjour. Je pars demain. Il pleut";
+        assert_eq!(
+            EditCommand::DeleteLastSentence.apply(note).unwrap(),
+            "Bonjour. Je pars demain."
+        );
+        assert_eq!(
+            EditCommand::DeleteLastSentence
+                .apply("Bonjour. Je pars demain.")
+                .unwrap(),
+            "Bonjour."
+        );
+        assert_eq!(
+            EditCommand::DeleteLastSentence.apply("Une seule").unwrap(),
+            ""
+        );
+        assert_eq!(
+            EditCommand::DeleteLastWord
+                .apply("Je pars, demain.")
+                .unwrap(),
+            "Je pars."
+        );
+    }
+
+    #[test]
+    fn test_replace_keeps_case_and_reports_missing_text() {
+        let replace = |find: &str, by: &str| EditCommand::Replace {
+            find: find.to_string(),
+            replace: by.to_string(),
+        };
+        assert_eq!(
+            replace("mardi", "mercredi")
+                .apply("Mardi ou mardi prochain")
+                .unwrap(),
+            "Mercredi ou mercredi prochain"
+        );
+        assert!(matches!(
+            replace("jeudi", "vendredi").apply("Mardi"),
+            Err(AppError::NoteEditFailed(_))
+        ));
+        assert!(matches!(
+            EditCommand::DeleteLastWord.apply("  "),
+            Err(AppError::NoteEditFailed(_))
+        ));
+    }
+}
@@ -0,0 +1,14 @@
+//! Note module - the current note and voice editing
+//!
+//! Submodules:
+//! - state: current note text with its undo history (managed by Tauri)
+//! - edit: voice editing commands ("supprime la dernière phrase", "undo")
+//!
+//! The note lives in the backend: a recording that starts with an editing
+//! command changes it instead of replacing it.
+
+pub mod edit;
+pub mod state;
+
+pub use edit::{parse_command, EditCommand};
+pub use state::{NoteSnapshot, NoteState};
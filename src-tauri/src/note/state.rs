@@ -0,0 +1,235 @@
+//! State module - the current note and its undo history
+//!
+//! Every change to the note (a new transcript, a voice edit) pushes the
+//! previous text on a bounded history, so "annule" / "undo" steps back one
+//! change at a time.
+//!
+//! In append mode ([note] append) each transcript is added to the note
+//! instead of replacing it. The note lives here rather than in the window so
+//! that recordings started by hotkey with the window hidden append too.
+
+use std::sync::Mutex;
+
+use serde::Serialize;
+
+use crate::config::loader::NoteConfig;
+use crate::error::AppError;
+use crate::note::edit::EditCommand;
+
+/// Nombre de versions précédentes gardées pour l'annulation
+const MAX_HISTORY: usize = 50;
+
+/// Note courante et ses versions précédentes, la plus récente en dernier.
+#[derive(Debug, Default)]
+struct Note {
+    text: String,
+    history: Vec<String>,
+}
+
+impl Note {
+    /// Remplace le texte en gardant l'ancien dans l'historique.
+    fn record(&mut self, text: String) {
+        if text == self.text {
+            return;
+        }
+        let previous = std::mem::replace(&mut self.text, text);
+        self.history.push(previous);
+        if self.history.len() > MAX_HISTORY {
+            self.history.remove(0);
+        }
+    }
+
+    fn snapshot(&self) -> NoteSnapshot {
+        NoteSnapshot {
+            text: self.text.clone(),
+            can_undo: !self.history.is_empty(),
+        }
+    }
+}
+
+/// Note courante telle qu'envoyée au frontend.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NoteSnapshot {
+    pub text: String,
+    pub can_undo: bool,
+}
+
+/// State managed by Tauri: the current note.
+#[derive(Debug, Default)]
+pub struct NoteState {
+    note: Mutex<Note>,
+}
+
+impl NoteState {
+    pub fn snapshot(&self) -> NoteSnapshot {
+        self.note.lock().expect("Note lock poisoned").snapshot()
+    }
+
+    /// Remplace la note par une nouvelle transcription.
+    pub fn replace(&self, text: String) -> NoteSnapshot {
+        let mut note = self.note.lock().expect("Note lock poisoned");
+        note.record(text);
+        note.snapshot()
+    }
+
+    /// Ajoute une transcription à la note en mode ajout ([note] append),
+    /// sinon la remplace.
+    pub fn add_transcript(&self, text: String, config: &NoteConfig) -> NoteSnapshot {
+        if config.append {
+            self.append(&text, config.separator.as_str())
+        } else {
+            self.replace(text)
+        }
+    }
+
+    /// Ajoute une transcription à la fin de la note, après `separator`.
+    ///
+    /// Une note vide commence directement par la transcription.
+    pub fn append(&self, text: &str, separator: &str) -> NoteSnapshot {
+        let mut note = self.note.lock().expect("Note lock poisoned");
+        let text = text.trim();
+        if !text.is_empty() {
+            let appended = if note.text.trim().is_empty() {
+                text.to_string()
+            } else {
+                format!("{}{}{}", note.text.trim_end(), separator, text)
+            };
+            note.record(appended);
+        }
+        note.snapshot()
+    }
+
+    /// Commence une nouvelle note; l'ancienne reste récupérable par annulation.
+    pub fn clear(&self) -> NoteSnapshot {
+        let mut note = self.note.lock().expect("Note lock poisoned");
+        note.record(String::new());
+        note.snapshot()
+    }
+
+    /// Exécute une commande d'édition, suivie de la dictée `rest`.
+    ///
+    /// La modification et la dictée forment une seule étape d'annulation.
+    ///
+    /// # Errors
+    /// - `NoteEditFailed` si rien n'est à annuler ou si la commande ne s'applique pas
+    pub fn execute(&self, command: &EditCommand, rest: &str) -> Result<NoteSnapshot, AppError> {
+        let mut note = self.note.lock().expect("Note lock poisoned");
+
+        let mut text = match command {
+            EditCommand::Undo => {
+                let previous = note.history.pop().ok_or_else(|| {
+                    AppError::NoteEditFailed(
+                        "aucune modification à annuler. Réessayez après une dictée.".to_string(),
+                    )
+                })?;
+                note.text = previous;
+                note.text.clone()
+            }
+            _ => command.apply(&note.text)?,
+        };
+        if !rest.is_empty() {
+            if !text.is_empty() {
+                text.push(' ');
+            }
+            text.push_str(rest);
+        }
+
+        if *command != EditCommand::Undo || !rest.is_empty() {
+            note.record(text);
+        }
+        Ok(note.snapshot())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edits_can_be_undone_in_order() {
+        let state = NoteState::default();
+        state.replace("Bonjour. Je pars demain.".to_string());
+        state
+            .execute(&EditCommand::DeleteLastSentence, "Je reste.")
+            .unwrap();
+        assert_eq!(state.snapshot().text, "Bonjour. Je reste.");
+
+        let undone = state.execute(&EditCommand::Undo, "").unwrap();
+        assert_eq!(undone.text, "Bonjour. Je pars demain.");
+        assert!(undone.can_undo, "The transcript itself can be undone");
+
+        state.execute(&EditCommand::Undo, "").unwrap();
+        assert_eq!(
+            state.snapshot(),
+            NoteSnapshot {
+                text: String::new(),
+                can_undo: false
+            }
+        );
+        assert!(matches!(
+            state.execute(&EditCommand::Undo, ""),
+            Err(AppError::NoteEditFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_failed_edit_leaves_note_unchanged() {
+        let state = NoteState::default();
+        state.replace("Mardi".to_string());
+        let replace = EditCommand::Replace {
+            find: "jeudi".to_string(),
+            replace: "vendredi".to_string(),
+        };
+
+        assert!(state.execute(&replace, "").is_err());
+        assert_eq!(state.snapshot().text, "Mardi");
+        state.execute(&EditCommand::Undo, "").unwrap();
+        assert_eq!(
+            state.snapshot().text,
+            "",
+            "Only the transcript was recorded"
+        );
+    }
+
+    #[test]
+    fn test_append_joins_transcripts_with_separator() {
+        let state = NoteState::default();
+        state.append("Bonjour Marie,", "\n\n");
+        state.append("  ", "\n\n");
+        state.append("Voici le compte rendu.", "\n\n");
+        assert_eq!(
+            state.append("Il est en pièce jointe.", " ").text,
+            "Bonjour Marie,\n\nVoici le compte rendu. Il est en pièce jointe."
+        );
+
+        assert_eq!(state.clear().text, "");
+        assert_eq!(state.append("Nouveau", " ").text, "Nouveau");
+        state.execute(&EditCommand::Undo, "").unwrap();
+        let restored = state.execute(&EditCommand::Undo, "").unwrap();
+        assert!(restored.text.ends_with("pièce jointe."));
+    }
+
+    #[test]
+    fn test_new_note_command_starts_with_following_dictation() {
+        let state = NoteState::default();
+        state.replace("Ancienne note.".to_string());
+        let note = state
+            .execute(&EditCommand::NewNote, "Objet: réunion.")
+            .unwrap();
+        assert_eq!(note.text, "Objet: réunion.");
+
+        // Sur une note vide, rien à effacer: pas d'étape d'annulation
+        let empty = NoteState::default();
+        assert!(!empty.execute(&EditCommand::NewNote, "").unwrap().can_undo);
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let state = NoteState::default();
+        for i in 0..MAX_HISTORY + 10 {
+            state.replace(i.to_string());
+        }
+        let note = state.note.lock().unwrap();
+        assert_eq!(note.history.len(), MAX_HISTORY);
+    }
+}
@@ -1,10 +1,12 @@
 //! Global hotkeys module - keyboard shortcuts
 //!
-//! Registers global keyboard shortcuts for recording toggle.
-//! Uses tauri-plugin-global-shortcut 2.x.
+//! Registers global keyboard shortcuts for recording toggle and for starting
+//! a new note (append mode). Uses tauri-plugin-global-shortcut 2.x.
 //!
 //! Note: toggle_recording delegates to commands::start_recording and
 //! commands::stop_recording to avoid code duplication (DRY principle).
+//! A hotkey stop also queues the transcription (commands::submit_recording):
+//! with the window hidden, no frontend is there to call start_transcription.
 
 use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
@@ -16,6 +18,9 @@ use crate::error::AppError;
 /// CmdOrCtrl = Cmd on macOS, Ctrl on Linux/Windows
 const DEFAULT_TOGGLE_RECORDING: &str = "CmdOrCtrl+Alt+R";
 
+/// Default shortcut for starting a new note
+const DEFAULT_NEW_NOTE: &str = "CmdOrCtrl+Alt+N";
+
 /// Registers global keyboard shortcuts for the application.
 ///
 /// # Arguments
@@ -69,11 +74,39 @@ pub fn register_global_shortcuts(app: &AppHandle) -> Result<(), AppError> {
     Ok(())
 }
 
+/// Registers the new note shortcut: the next recordings start a fresh note,
+/// even with the window hidden (the note lives in backend state).
+///
+/// # Errors
+/// Returns `AppError::HotkeyRegistrationFailed` if registration fails (not fatal).
+pub fn register_new_note_shortcut(app: &AppHandle) -> Result<(), AppError> {
+    let shortcut: Shortcut = DEFAULT_NEW_NOTE
+        .parse()
+        .map_err(|e| AppError::HotkeyRegistrationFailed(format!("Invalid shortcut format: {}", e)))?;
+
+    // on_shortcut registers the shortcut along with its handler
+    app.global_shortcut()
+        .on_shortcut(shortcut, |app, _shortcut, event| {
+            if event.state != ShortcutState::Pressed {
+                return;
+            }
+            commands::new_note(app.clone(), app.state());
+            println!("New note started via hotkey");
+        })
+        .map_err(|e| AppError::HotkeyRegistrationFailed(e.to_string()))?;
+
+    println!("Global shortcut registered: {}", DEFAULT_NEW_NOTE);
+
+    Ok(())
+}
+
 /// Toggle recording state: start if idle, stop if recording.
 ///
 /// Delegates to commands::start_recording and commands::stop_recording
 /// to avoid code duplication (DRY principle). The commands handle all
-/// locking, event emission, and waveform channel setup.
+/// locking, event emission, and waveform channel setup. On stop, the
+/// recording is queued like start_transcription does, with the request
+/// defaults (config.toml and active profile).
 async fn toggle_recording(app: &AppHandle) -> Result<(), AppError> {
     let state: tauri::State<'_, AudioState> = app.state();
 
@@ -91,6 +124,8 @@ async fn toggle_recording(app: &AppHandle) -> Result<(), AppError> {
         match commands::stop_recording(app.state(), app.clone()).await {
             Ok(wav_path) => {
                 println!("Recording stopped via hotkey: {}", wav_path);
+                let job_id = commands::submit_recording(app, &wav_path, None)?;
+                println!("Transcription job {} queued via hotkey", job_id);
             }
             Err(e) => {
                 eprintln!("Failed to stop recording via hotkey: {:?}", e);
@@ -126,11 +161,22 @@ pub fn unregister_all(app: &AppHandle) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::loader::NoteConfig;
+    use crate::config::AppConfig;
+    use crate::note::edit::ParsedCommand;
+    use crate::note::NoteState;
+    use crate::transcription::mock::MockEngine;
+    use crate::transcription::queue::Job;
+    use crate::transcription::worker::{run_job, JobEvents};
+    use crate::transcription::{
+        ChunkResult, JobId, JobQueueState, Transcript, TranscriptionOptions, UserFiles,
+    };
 
     #[test]
     fn test_default_shortcut_constant() {
         // Verify the constant is correctly defined
         assert_eq!(DEFAULT_TOGGLE_RECORDING, "CmdOrCtrl+Alt+R");
+        assert_eq!(DEFAULT_NEW_NOTE, "CmdOrCtrl+Alt+N");
     }
 
     #[test]
@@ -138,5 +184,57 @@ mod tests {
         // Verify the shortcut string can be parsed
         let result: Result<Shortcut, _> = DEFAULT_TOGGLE_RECORDING.parse();
         assert!(result.is_ok(), "Shortcut should parse successfully");
+        let result: Result<Shortcut, _> = DEFAULT_NEW_NOTE.parse();
+        assert!(result.is_ok(), "New note shortcut should parse successfully");
+    }
+
+    /// Événements du worker réduits à la note, comme `TauriJobEvents`.
+    struct NoteEvents<'a> {
+        notes: &'a NoteState,
+        config: &'a NoteConfig,
+    }
+
+    impl JobEvents for NoteEvents<'_> {
+        fn progress(&self, _job_id: JobId, _percent: i32) {}
+        fn chunk(&self, _job_id: JobId, _chunk: &ChunkResult) {}
+        fn complete(&self, _job_id: JobId, transcript: Transcript, _audio_deleted: bool) {
+            self.notes.add_transcript(transcript.text, self.config);
+        }
+        fn edit(&self, _job_id: JobId, _command: ParsedCommand, _audio_deleted: bool) {}
+        fn cancelled(&self, _job_id: JobId, _audio_deleted: bool) {}
+        fn failed(&self, _job_id: JobId, error: &AppError, _audio_deleted: bool) {
+            panic!("Unexpected failure: {}", error);
+        }
+        fn keep_audio(&self, _job: &Job) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn test_hotkey_stop_queues_transcription_appended_to_note() {
+        let audio = AudioState::default();
+        let jobs = JobQueueState::default();
+        let notes = NoteState::default();
+        let mut config = AppConfig::default();
+        config.note.append = true;
+        notes.add_transcript("Première prise.".to_string(), &config.note);
+
+        // Arrêt au raccourci en mode mémoire: stop_recording rend cet identifiant
+        let recording = audio.memory.insert(vec![0.0; 16]);
+        let options =
+            TranscriptionOptions::resolve(&config, &UserFiles::default(), &Default::default())
+                .unwrap();
+        let job_id = commands::enqueue_recording(&jobs, &audio, &recording, options).unwrap();
+
+        let job = jobs.queue.lock().unwrap().start_next().unwrap();
+        assert_eq!(job.id, job_id);
+        let engine = MockEngine::new().then_text("Seconde prise.");
+        let events = NoteEvents {
+            notes: &notes,
+            config: &config.note,
+        };
+        run_job(&engine, &events, &job);
+
+        assert_eq!(notes.snapshot().text, "Première prise. Seconde prise.");
     }
 }
@@ -4,9 +4,10 @@
 //! Prepares for future Ghost Mode (Epic 5) where close != quit.
 
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::error::AppError;
+use crate::transcription::chunking::checkpoint_path;
 
 /// Returns the path to the application's temporary directory.
 ///
@@ -22,6 +23,11 @@ fn get_temp_dir() -> PathBuf {
 
 /// Cleans up all temporary files (*.wav) from the temp directory.
 ///
+/// Recordings with a chunked-transcription checkpoint are kept so the
+/// transcription can resume; checkpoints without their recording are removed.
+/// An explicitly cancelled job has already deleted both (NFR-SEC-3): only a
+/// job interrupted by a crash or a shutdown leaves a checkpoint behind.
+///
 /// # Returns
 /// Returns `Ok(())` on success. Logs the number of orphaned files removed if any.
 ///
@@ -30,14 +36,51 @@ fn get_temp_dir() -> PathBuf {
 /// Individual file deletion failures are logged but don't cause the function to fail.
 /// Silently succeeds if the temp directory doesn't exist.
 pub fn cleanup_temp_files() -> Result<(), AppError> {
+    cleanup_dir(&get_temp_dir())
+}
+
+/// Startup variant of `cleanup_temp_files` (crash recovery).
+///
+/// Also removes checkpoints left half-written (*.checkpoint.tmp) by a crash:
+/// nothing writes them before the transcription worker starts.
+///
+/// # Errors
+/// See `cleanup_temp_files`.
+pub fn cleanup_temp_files_at_startup() -> Result<(), AppError> {
     let temp_dir = get_temp_dir();
+    remove_partial_checkpoints(&temp_dir);
+    cleanup_dir(&temp_dir)
+}
+
+/// Removes half-written checkpoints (*.checkpoint.tmp) from `temp_dir`.
+fn remove_partial_checkpoints(temp_dir: &Path) {
+    let Ok(entries) = fs::read_dir(temp_dir) else {
+        return;
+    };
+    for path in entries.flatten().map(|entry| entry.path()) {
+        let partial = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.ends_with(".checkpoint.tmp"));
+        if partial {
+            if let Err(e) = fs::remove_file(&path) {
+                eprintln!(
+                    "Warning: Could not remove partial checkpoint {:?}: {}",
+                    path, e
+                );
+            }
+        }
+    }
+}
 
+/// Cleans up temporary files from `temp_dir` (see `cleanup_temp_files`).
+fn cleanup_dir(temp_dir: &Path) -> Result<(), AppError> {
     if !temp_dir.exists() {
         // No temp directory, nothing to clean
         return Ok(());
     }
 
-    let entries = fs::read_dir(&temp_dir).map_err(|e| {
+    let entries = fs::read_dir(temp_dir).map_err(|e| {
         AppError::IoError(format!("Cannot read temp directory: {}", e))
     })?;
 
@@ -45,7 +88,20 @@ pub fn cleanup_temp_files() -> Result<(), AppError> {
 
     for entry in entries.flatten() {
         let path = entry.path();
-        if path.extension().map_or(false, |ext| ext == "wav") {
+
+        if let Some(audio_path) = checkpoint_audio_path(&path) {
+            if !audio_path.exists() {
+                let _ = fs::remove_file(&path);
+            }
+            continue;
+        }
+
+        if path.extension().is_some_and(|ext| ext == "wav") {
+            // Transcription découpée interrompue: reprise au prochain démarrage
+            if checkpoint_path(&path).exists() {
+                println!("Keeping resumable recording: {}", path.display());
+                continue;
+            }
             if let Err(e) = fs::remove_file(&path) {
                 // Log but don't fail - best effort cleanup
                 eprintln!("Warning: Could not remove temp file {:?}: {}", path, e);
@@ -66,6 +122,13 @@ pub fn cleanup_temp_files() -> Result<(), AppError> {
     Ok(())
 }
 
+/// Returns the recording a checkpoint file belongs to, if `path` is a checkpoint.
+fn checkpoint_audio_path(path: &Path) -> Option<PathBuf> {
+    let name = path.file_name()?.to_str()?;
+    let stem = name.strip_suffix(".checkpoint.json")?;
+    Some(path.with_file_name(format!("{}.wav", stem)))
+}
+
 /// Performs a graceful shutdown of the application.
 ///
 /// This function:
@@ -300,6 +363,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cleanup_keeps_resumable_recordings() {
+        let test_dir = get_isolated_test_dir("keeps_resumable");
+        let _guard = TestDirGuard { path: test_dir.clone() };
+        fs::create_dir_all(&test_dir).unwrap();
+
+        let resumable = test_dir.join("recording-1-0.wav");
+        let checkpoint = test_dir.join("recording-1-0.checkpoint.json");
+        let orphan_checkpoint = test_dir.join("recording-2-0.checkpoint.json");
+        let plain = test_dir.join("recording-3-0.wav");
+        for f in [&resumable, &checkpoint, &orphan_checkpoint, &plain] {
+            File::create(f).unwrap();
+        }
+
+        cleanup_dir(&test_dir).unwrap();
+
+        assert!(resumable.exists(), "Recording with checkpoint should be kept");
+        assert!(checkpoint.exists());
+        assert!(!orphan_checkpoint.exists(), "Checkpoint without recording should be removed");
+        assert!(!plain.exists());
+    }
+
+    #[test]
+    fn test_startup_removes_partial_checkpoints() {
+        let test_dir = get_isolated_test_dir("partial_checkpoint");
+        let _guard = TestDirGuard { path: test_dir.clone() };
+        fs::create_dir_all(&test_dir).unwrap();
+
+        let partial = test_dir.join("recording-1-0.checkpoint.tmp");
+        let checkpoint = test_dir.join("recording-1-0.checkpoint.json");
+        for f in [&partial, &checkpoint] {
+            File::create(f).unwrap();
+        }
+
+        remove_partial_checkpoints(&test_dir);
+
+        assert!(!partial.exists(), "Half-written checkpoint should be removed");
+        assert!(checkpoint.exists());
+    }
+
     #[test]
     fn test_cleanup_handles_empty_temp_dir() {
         // Edge case: temp dir exists but is empty
@@ -0,0 +1,213 @@
+//! Cleanup module - language-aware tidying of spoken text
+//!
+//! Spoken French and English are full of hesitations ("euh", "um") and
+//! stutters ("le le chat") that Whisper transcribes faithfully. These
+//! transforms work line by line, so speaker turns stay on their own lines
+//! and keep their labels (`Transcript::labelled_text`).
+
+use crate::config::loader::LanguageRules;
+
+/// Ponctuation de fin de phrase
+const SENTENCE_END: &[char] = &['.', '!', '?', '…'];
+
+/// Sépare un mot de sa ponctuation finale ("chat," → ("chat", ",")).
+fn split_word(token: &str) -> (&str, &str) {
+    let word = token.trim_end_matches(|c: char| !c.is_alphanumeric());
+    (word, &token[word.len()..])
+}
+
+/// Indique si la ponctuation termine une phrase.
+fn ends_sentence(punctuation: &str) -> bool {
+    punctuation.contains(SENTENCE_END)
+}
+
+/// Indique si `word` figure dans `list`, sans tenir compte de la casse.
+fn contains_word(list: &[String], word: &str) -> bool {
+    let word = word.to_lowercase();
+    list.iter().any(|w| w.to_lowercase() == word)
+}
+
+/// Applique `transform` à chaque ligne du texte.
+fn map_lines(text: &str, transform: impl Fn(&str) -> String) -> String {
+    text.lines().map(transform).collect::<Vec<_>>().join("\n")
+}
+
+/// Réduit les espaces: un seul entre deux mots, aucun avant une virgule ou
+/// un point, ni en bord de ligne.
+pub fn normalize_whitespace(text: &str) -> String {
+    let text = map_lines(text, |line| {
+        line.split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .replace(" ,", ",")
+            .replace(" .", ".")
+    });
+    text.trim().to_string()
+}
+
+/// Retire les mots d'hésitation de la langue.
+///
+/// La virgule qui suit un mot retiré disparaît avec lui; un point final est
+/// reporté sur le mot précédent. Un mot en majuscule est gardé ("Ben" le
+/// prénom, pas "ben" l'hésitation), sauf en début de phrase s'il est suivi
+/// d'une ponctuation ("Ben, on y va.").
+pub fn remove_fillers(text: &str, rules: &LanguageRules) -> String {
+    if rules.fillers.is_empty() {
+        return text.to_string();
+    }
+
+    map_lines(text, |line| {
+        let mut kept: Vec<String> = Vec::new();
+        let mut sentence_start = true;
+
+        for token in line.split_whitespace() {
+            let (word, punctuation) = split_word(token);
+            let lowercase = word.chars().next().is_some_and(char::is_lowercase);
+            let detached = sentence_start && !punctuation.is_empty();
+
+            if contains_word(&rules.fillers, word) && (lowercase || detached) {
+                if ends_sentence(punctuation) {
+                    if let Some(previous) = kept.last_mut() {
+                        let end = previous.trim_end_matches([',', ';']).len();
+                        previous.truncate(end);
+                        previous.push_str(punctuation);
+                    }
+                    sentence_start = true;
+                }
+                continue;
+            }
+
+            sentence_start = ends_sentence(punctuation);
+            kept.push(token.to_string());
+        }
+
+        kept.join(" ")
+    })
+}
+
+/// Ne garde qu'une occurrence d'un mot répété à la suite ("le le chat").
+///
+/// Une répétition séparée par une ponctuation est voulue ("non, non") et
+/// conservée, comme les mots autorisés de la langue ("nous nous sommes").
+pub fn collapse_duplicates(text: &str, rules: &LanguageRules) -> String {
+    map_lines(text, |line| {
+        let mut kept: Vec<String> = Vec::new();
+
+        for token in line.split_whitespace() {
+            let (word, punctuation) = split_word(token);
+            let repeated = kept.last().is_some_and(|previous| {
+                let (previous_word, previous_punctuation) = split_word(previous);
+                previous_punctuation.is_empty()
+                    && previous_word.to_lowercase() == word.to_lowercase()
+                    && word.chars().any(char::is_alphabetic)
+                    && !contains_word(&rules.allowed_repeats, word)
+            });
+
+            match kept.last_mut() {
+                // La ponctuation de la répétition passe à l'occurrence gardée
+                Some(previous) if repeated => previous.push_str(punctuation),
+                _ => kept.push(token.to_string()),
+            }
+        }
+
+        kept.join(" ")
+    })
+}
+
+/// Met une majuscule au début de chaque ligne et après chaque fin de phrase.
+///
+/// Une fin de phrase compte si elle est suivie d'un espace: "3.5" et
+/// "exemple.com" restent intacts.
+pub fn fix_capitalization(text: &str) -> String {
+    map_lines(text, |line| {
+        let mut result = String::with_capacity(line.len());
+        let mut capitalize = true;
+        let mut after_sentence_end = false;
+
+        for c in line.chars() {
+            if c.is_alphanumeric() {
+                if capitalize {
+                    result.extend(c.to_uppercase());
+                } else {
+                    result.push(c);
+                }
+                capitalize = false;
+                after_sentence_end = false;
+                continue;
+            }
+
+            // Guillemets et parenthèses laissent la majuscule au mot suivant
+            if SENTENCE_END.contains(&c) {
+                after_sentence_end = true;
+            } else if c.is_whitespace() && after_sentence_end {
+                capitalize = true;
+            }
+            result.push(c);
+        }
+
+        result
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn french() -> LanguageRules {
+        LanguageRules {
+            fillers: vec!["euh".to_string(), "ben".to_string()],
+            allowed_repeats: vec!["nous".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_normalize_whitespace() {
+        assert_eq!(
+            normalize_whitespace("  Bonjour ,  tout   le monde .\n Suite  "),
+            "Bonjour, tout le monde.\nSuite"
+        );
+    }
+
+    #[test]
+    fn test_remove_fillers_with_their_comma() {
+        assert_eq!(
+            remove_fillers("Euh, je pense que euh on peut.", &french()),
+            "je pense que on peut."
+        );
+        assert_eq!(remove_fillers("On y va, euh.", &french()), "On y va.");
+    }
+
+    #[test]
+    fn test_remove_fillers_keeps_capitalized_names() {
+        assert_eq!(remove_fillers("Ben est venu.", &french()), "Ben est venu.");
+        assert_eq!(
+            remove_fillers("Ben, j'ai vu Ben hier.", &french()),
+            "j'ai vu Ben hier."
+        );
+    }
+
+    #[test]
+    fn test_collapse_duplicates() {
+        assert_eq!(
+            collapse_duplicates("Le le chat dort dort.", &french()),
+            "Le chat dort."
+        );
+        assert_eq!(
+            collapse_duplicates("Non, non merci.", &french()),
+            "Non, non merci."
+        );
+        assert_eq!(
+            collapse_duplicates("Nous nous sommes vus.", &french()),
+            "Nous nous sommes vus."
+        );
+    }
+
+    #[test]
+    fn test_fix_capitalization_after_sentence_breaks() {
+        assert_eq!(
+            fix_capitalization("je pense. c'est bon? «oui» dit-il.\nsuite 3.5 km"),
+            "Je pense. C'est bon? «Oui» dit-il.\nSuite 3.5 km"
+        );
+    }
+}
@@ -0,0 +1,299 @@
+//! Code module - dictation of identifiers and symbols
+//!
+//! For commit messages and code comments: "camel case user id" becomes
+//! `userId` and "open paren" becomes "(". A case command formats the words
+//! that follow it, up to the next punctuation mark, spoken symbol or case
+//! command. Symbol names come from a per-language table (built-in, extended
+//! in config.toml); the longest matching name wins.
+
+use crate::config::loader::LanguageRules;
+use crate::text::dictation::normalize;
+
+/// Casse d'un identifiant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Case {
+    Camel,
+    Pascal,
+    Snake,
+    Kebab,
+    Constant,
+}
+
+/// Commandes de casse, les mêmes dans toutes les langues
+const CASES: &[(&str, Case)] = &[
+    ("camel case", Case::Camel),
+    ("pascal case", Case::Pascal),
+    ("snake case", Case::Snake),
+    ("kebab case", Case::Kebab),
+    ("constant case", Case::Constant),
+    ("screaming snake case", Case::Constant),
+];
+
+/// Symboles collés à leurs deux voisins ("user.name", "main(")
+const ATTACH_BOTH: &[&str] = &[".", "::", "_", "/", "\\", "(", "["];
+
+/// Symboles collés au mot précédent
+const ATTACH_LEFT: &[&str] = &[")", "]", ",", ";", ":", "?"];
+
+/// Symboles collés au mot suivant
+const ATTACH_RIGHT: &[&str] = &["!", "#", "@", "$", "&", "~"];
+
+/// Symboles ouvrants puis fermants, en alternance
+const QUOTES: &[&str] = &["\"", "'", "`"];
+
+/// Première lettre en majuscule, le reste en minuscules.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+impl Case {
+    fn format(self, words: &[String]) -> String {
+        let joined = |separator: &str, upper: bool| {
+            words
+                .iter()
+                .map(|w| {
+                    if upper {
+                        w.to_uppercase()
+                    } else {
+                        w.to_lowercase()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(separator)
+        };
+        match self {
+            Case::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| {
+                    if i == 0 {
+                        w.to_lowercase()
+                    } else {
+                        capitalize(w)
+                    }
+                })
+                .collect(),
+            Case::Pascal => words.iter().map(|w| capitalize(w)).collect(),
+            Case::Snake => joined("_", false),
+            Case::Kebab => joined("-", false),
+            Case::Constant => joined("_", true),
+        }
+    }
+}
+
+/// Ligne en cours d'écriture, avec l'espacement propre à chaque symbole.
+#[derive(Debug, Default)]
+struct Line {
+    text: String,
+    /// Le dernier morceau se colle au suivant
+    glue: bool,
+    open_quotes: Vec<String>,
+}
+
+impl Line {
+    fn push(&mut self, piece: &str, attach_left: bool, glue_right: bool) {
+        if !self.text.is_empty() && !self.glue && !attach_left {
+            self.text.push(' ');
+        }
+        self.text.push_str(piece);
+        self.glue = glue_right;
+    }
+
+    fn push_word(&mut self, word: &str) {
+        self.push(word, false, false);
+    }
+
+    fn push_symbol(&mut self, symbol: &str) {
+        let (attach_left, glue_right) = if QUOTES.contains(&symbol) {
+            match self.open_quotes.iter().position(|q| q == symbol) {
+                Some(open) => {
+                    self.open_quotes.remove(open);
+                    (true, false)
+                }
+                None => {
+                    self.open_quotes.push(symbol.to_string());
+                    (false, true)
+                }
+            }
+        } else {
+            (
+                ATTACH_BOTH.contains(&symbol) || ATTACH_LEFT.contains(&symbol),
+                ATTACH_BOTH.contains(&symbol) || ATTACH_RIGHT.contains(&symbol),
+            )
+        };
+        self.push(symbol, attach_left, glue_right);
+    }
+}
+
+/// Table de phrases découpées en mots, les plus longues d'abord.
+fn split_phrases<'a, T: Copy>(
+    phrases: impl Iterator<Item = (&'a str, T)>,
+) -> Vec<(Vec<String>, T)> {
+    let mut table: Vec<(Vec<String>, T)> = phrases
+        .map(|(phrase, value)| (phrase.split_whitespace().map(normalize).collect(), value))
+        .filter(|(words, _): &(Vec<String>, T)| !words.is_empty())
+        .collect();
+    table.sort_by_key(|(words, _)| std::cmp::Reverse(words.len()));
+    table
+}
+
+/// Phrase de la table commençant au mot `i`: (nombre de mots, valeur).
+fn find<T: Copy>(
+    table: &[(Vec<String>, T)],
+    normalized: &[String],
+    i: usize,
+) -> Option<(usize, T)> {
+    table
+        .iter()
+        .find(|(words, _)| {
+            normalized
+                .get(i..i + words.len())
+                .is_some_and(|candidate| candidate == words.as_slice())
+        })
+        .map(|(words, value)| (words.len(), *value))
+}
+
+/// Remplace les commandes de casse et les noms de symboles par du code.
+pub fn apply_code_dictation(text: &str, rules: &LanguageRules) -> String {
+    let cases = split_phrases(CASES.iter().copied());
+    let symbols = split_phrases(
+        rules
+            .symbols
+            .iter()
+            .map(|(name, symbol)| (name.as_str(), symbol.as_str())),
+    );
+    let ends_with_word = |token: &str| token.ends_with(char::is_alphanumeric);
+
+    text.lines()
+        .map(|line| {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let normalized: Vec<String> = tokens.iter().map(|t| normalize(t)).collect();
+            let is_command = |j: usize| {
+                find(&cases, &normalized, j).is_some() || find(&symbols, &normalized, j).is_some()
+            };
+            let mut out = Line::default();
+            let mut i = 0;
+
+            while i < tokens.len() {
+                if let Some((len, case)) = find(&cases, &normalized, i) {
+                    let mut words: Vec<String> = Vec::new();
+                    let mut trailing = "";
+                    let mut j = i + len;
+                    // "snake case." ne formate rien
+                    let open = ends_with_word(tokens[j - 1]);
+                    while open && j < tokens.len() && !is_command(j) {
+                        let token = tokens[j];
+                        let body = token.trim_end_matches(|c: char| !c.is_alphanumeric());
+                        words.extend(
+                            body.trim_start_matches(|c: char| !c.is_alphanumeric())
+                                .split(['-', '_'])
+                                .filter(|w| !w.is_empty())
+                                .map(str::to_string),
+                        );
+                        j += 1;
+                        if body.len() < token.len() {
+                            trailing = &token[body.len()..];
+                            break;
+                        }
+                    }
+
+                    if words.is_empty() {
+                        // Rien à formater: la commande reste du texte
+                        for token in &tokens[i..i + len] {
+                            out.push_word(token);
+                        }
+                        i += len;
+                        continue;
+                    }
+                    out.push_word(&case.format(&words));
+                    if !trailing.is_empty() {
+                        out.push(trailing, true, false);
+                    }
+                    i = j;
+                } else if let Some((len, symbol)) = find(&symbols, &normalized, i) {
+                    out.push_symbol(symbol);
+                    i += len;
+                } else {
+                    out.push_word(tokens[i]);
+                    i += 1;
+                }
+            }
+            out.text
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::loader::PostProcessConfig;
+
+    fn code(text: &str, language: &str) -> String {
+        apply_code_dictation(text, &PostProcessConfig::default().rules(Some(language)))
+    }
+
+    #[test]
+    fn test_case_commands() {
+        assert_eq!(code("camel case user id", "en"), "userId");
+        assert_eq!(code("pascal case HTTP client", "en"), "HttpClient");
+        assert_eq!(code("snake case user-id", "en"), "user_id");
+        assert_eq!(code("kebab case main menu", "en"), "main-menu");
+        assert_eq!(code("constant case max retries.", "en"), "MAX_RETRIES.");
+    }
+
+    #[test]
+    fn test_identifier_ends_at_punctuation_or_symbol() {
+        assert_eq!(
+            code("Renamed snake case user id, then pushed", "en"),
+            "Renamed user_id, then pushed"
+        );
+        assert_eq!(
+            code(
+                "fix user dot camel case get name open paren close paren",
+                "en"
+            ),
+            "fix user.getName()"
+        );
+    }
+
+    #[test]
+    fn test_symbol_spacing() {
+        assert_eq!(
+            code("fn main open paren close paren arrow result", "en"),
+            "fn main() -> result"
+        );
+        assert_eq!(code("if x double equals y", "en"), "if x == y");
+        assert_eq!(
+            code(
+                "print open paren double quote hello double quote close paren",
+                "en"
+            ),
+            "print(\"hello\")"
+        );
+    }
+
+    #[test]
+    fn test_french_symbols() {
+        assert_eq!(
+            code(
+                "appelle camel case numéro client ouvre parenthèse ferme parenthèse",
+                "fr"
+            ),
+            "appelle numéroClient()"
+        );
+    }
+
+    #[test]
+    fn test_case_command_without_words_stays_text() {
+        assert_eq!(code("what is snake case", "en"), "what is snake case");
+        assert_eq!(code("snake case.", "en"), "snake case.");
+    }
+}
@@ -0,0 +1,157 @@
+//! Dictation module - spoken punctuation and layout commands
+//!
+//! When dictating, "virgule" or "new line" is an instruction, not text.
+//! Commands come from a per-language table (built-in, extended in
+//! config.toml); the longest matching phrase wins, so "point à la ligne" is
+//! not read as "point" followed by "à la ligne". Punctuation Whisper puts
+//! around a command word is replaced by the command's own output.
+
+use crate::config::loader::LanguageRules;
+
+/// Sorties collées au mot précédent
+const ATTACH_LEFT: &[char] = &[',', '.', ';', ':', '!', '?', '…', ')', '»', '”'];
+
+/// Sorties collées au mot suivant
+const ATTACH_RIGHT: &[char] = &['(', '«', '“'];
+
+/// Ponctuation ajoutée par Whisper autour d'une commande prononcée
+const SPOKEN_PUNCTUATION: &[char] = &[',', '.', ';', ':', '!', '?', '…'];
+
+/// Forme comparable d'un mot: minuscules, sans ponctuation autour, apostrophe droite.
+pub(crate) fn normalize(token: &str) -> String {
+    token
+        .trim_matches(|c: char| !c.is_alphanumeric())
+        .replace('’', "'")
+        .to_lowercase()
+}
+
+/// Ajoute un mot, précédé d'un espace sauf en début de ligne ou après une ouverture.
+fn push_word(out: &mut String, word: &str) {
+    if !out.is_empty() && !out.ends_with('\n') && !out.ends_with(ATTACH_RIGHT) {
+        out.push(' ');
+    }
+    out.push_str(word);
+}
+
+/// Ajoute la sortie d'une commande à la place de la ponctuation de Whisper.
+fn push_command(out: &mut String, output: &str) {
+    if output.starts_with(ATTACH_LEFT) {
+        let end = out.trim_end().trim_end_matches(SPOKEN_PUNCTUATION).len();
+        out.truncate(end);
+        out.push_str(output);
+    } else if output.starts_with('\n') {
+        out.truncate(out.trim_end().len());
+        out.push_str(output);
+    } else {
+        push_word(out, output);
+    }
+}
+
+/// Remplace les commandes de dictée de la langue par leur ponctuation ou mise en page.
+pub fn apply_commands(text: &str, rules: &LanguageRules) -> String {
+    if rules.commands.is_empty() {
+        return text.to_string();
+    }
+
+    // Phrases découpées en mots, les plus longues d'abord
+    let mut commands: Vec<(Vec<String>, &str)> = rules
+        .commands
+        .iter()
+        .map(|(phrase, output)| {
+            let words: Vec<String> = phrase.split_whitespace().map(normalize).collect();
+            (words, output.as_str())
+        })
+        .filter(|(words, _)| !words.is_empty())
+        .collect();
+    commands.sort_by_key(|(words, _)| std::cmp::Reverse(words.len()));
+
+    let lines: Vec<String> = text
+        .lines()
+        .map(|line| {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let normalized: Vec<String> = tokens.iter().map(|t| normalize(t)).collect();
+            let mut out = String::new();
+            let mut i = 0;
+
+            while i < tokens.len() {
+                let matched = commands.iter().find(|(words, _)| {
+                    normalized
+                        .get(i..i + words.len())
+                        .is_some_and(|candidate| candidate == words.as_slice())
+                });
+                match matched {
+                    Some((words, output)) => {
+                        push_command(&mut out, output);
+                        i += words.len();
+                    }
+                    None => {
+                        push_word(&mut out, tokens[i]);
+                        i += 1;
+                    }
+                }
+            }
+            out
+        })
+        .collect();
+
+    lines.join("\n").trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::loader::PostProcessConfig;
+
+    fn rules(language: &str) -> LanguageRules {
+        PostProcessConfig::default().rules(Some(language))
+    }
+
+    #[test]
+    fn test_french_punctuation_commands() {
+        assert_eq!(
+            apply_commands(
+                "Bonjour virgule comment allez-vous point d'interrogation",
+                &rules("fr")
+            ),
+            "Bonjour, comment allez-vous?"
+        );
+    }
+
+    #[test]
+    fn test_longest_phrase_wins_and_whisper_punctuation_is_replaced() {
+        assert_eq!(
+            apply_commands(
+                "Le point important, point à la ligne. Nouveau paragraphe. Suite.",
+                &rules("fr")
+            ),
+            "Le point important.\n\nSuite."
+        );
+    }
+
+    #[test]
+    fn test_quotes_attach_to_quoted_words() {
+        assert_eq!(
+            apply_commands(
+                "Il a dit ouvrez les guillemets oui fermez les guillemets point final",
+                &rules("fr")
+            ),
+            "Il a dit «oui»."
+        );
+    }
+
+    #[test]
+    fn test_english_commands() {
+        assert_eq!(
+            apply_commands("Dear Sam, comma, new line thanks period", &rules("en")),
+            "Dear Sam,\nthanks."
+        );
+    }
+
+    #[test]
+    fn test_no_table_leaves_text_unchanged() {
+        assert_eq!(
+            apply_commands("virgule", &LanguageRules::default()),
+            "virgule"
+        );
+    }
+}
@@ -0,0 +1,23 @@
+//! Text module - post-processing of transcribed text
+//!
+//! Submodules:
+//! - pipeline: ordered chain of transforms applied to a finished transcript
+//! - cleanup: fillers, duplicated words, capitalization and whitespace
+//! - numbers: spoken numbers, dates and times written in digits
+//! - code: identifier casing and spoken symbols ("camel case user id", "open paren")
+//! - dictation: spoken punctuation and layout commands ("virgule", "new line")
+//! - typography: French spacing, guillemets, apostrophes and ellipses
+//! - rules: user-defined literal and regex replacements (rules.toml)
+//!
+//! Whisper's raw output stays available next to the processed text.
+
+pub mod cleanup;
+pub mod code;
+pub mod dictation;
+pub mod numbers;
+pub mod pipeline;
+pub mod rules;
+pub mod typography;
+
+pub use pipeline::{process_excerpt, process_text, process_transcript};
+pub use rules::ReplacementRule;
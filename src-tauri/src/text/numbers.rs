@@ -0,0 +1,1072 @@
+//! Numbers module - inverse text normalization for French and English
+//!
+//! Whisper writes "vingt-trois" in one sentence and "23" in the next. This
+//! step rewrites spoken numbers in digits, with each language's written
+//! conventions: ordinals (23e, 23rd), decimals (3,5 / 3.5), percentages,
+//! currencies, dates and clock times (15h30, 3:30 pm).
+//!
+//! A lone word that is also an article or an adjective ("un", "neuf", "one",
+//! "first") stays a word unless a unit, a month or a time makes it a number.
+
+/// Langues prises en charge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Language {
+    French,
+    English,
+}
+
+/// Mot pouvant composer un nombre.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NumberWord {
+    /// 0 à 99 en un seul mot ("dix-sept" en compte deux)
+    Unit(u64),
+    /// cent, hundred
+    Hundred,
+    /// mille, million, milliard...
+    Scale(u64),
+    /// "et" de "vingt et un", "and" de "one hundred and five"
+    And,
+}
+
+/// Forme d'un ordinal, pour l'écrire ("1re", "2es").
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Ordinal {
+    /// premier, première (1er / 1re)
+    first: bool,
+    feminine: bool,
+    plural: bool,
+}
+
+const FRENCH_WORDS: &[(&str, NumberWord)] = &[
+    ("zéro", NumberWord::Unit(0)),
+    ("un", NumberWord::Unit(1)),
+    ("une", NumberWord::Unit(1)),
+    ("deux", NumberWord::Unit(2)),
+    ("trois", NumberWord::Unit(3)),
+    ("quatre", NumberWord::Unit(4)),
+    ("cinq", NumberWord::Unit(5)),
+    ("six", NumberWord::Unit(6)),
+    ("sept", NumberWord::Unit(7)),
+    ("huit", NumberWord::Unit(8)),
+    ("neuf", NumberWord::Unit(9)),
+    ("dix", NumberWord::Unit(10)),
+    ("onze", NumberWord::Unit(11)),
+    ("douze", NumberWord::Unit(12)),
+    ("treize", NumberWord::Unit(13)),
+    ("quatorze", NumberWord::Unit(14)),
+    ("quinze", NumberWord::Unit(15)),
+    ("seize", NumberWord::Unit(16)),
+    ("vingt", NumberWord::Unit(20)),
+    ("vingts", NumberWord::Unit(20)),
+    ("trente", NumberWord::Unit(30)),
+    ("quarante", NumberWord::Unit(40)),
+    ("cinquante", NumberWord::Unit(50)),
+    ("soixante", NumberWord::Unit(60)),
+    ("cent", NumberWord::Hundred),
+    ("cents", NumberWord::Hundred),
+    ("mille", NumberWord::Scale(1_000)),
+    ("million", NumberWord::Scale(1_000_000)),
+    ("millions", NumberWord::Scale(1_000_000)),
+    ("milliard", NumberWord::Scale(1_000_000_000)),
+    ("milliards", NumberWord::Scale(1_000_000_000)),
+    ("et", NumberWord::And),
+];
+
+const ENGLISH_WORDS: &[(&str, NumberWord)] = &[
+    ("zero", NumberWord::Unit(0)),
+    ("one", NumberWord::Unit(1)),
+    ("two", NumberWord::Unit(2)),
+    ("three", NumberWord::Unit(3)),
+    ("four", NumberWord::Unit(4)),
+    ("five", NumberWord::Unit(5)),
+    ("six", NumberWord::Unit(6)),
+    ("seven", NumberWord::Unit(7)),
+    ("eight", NumberWord::Unit(8)),
+    ("nine", NumberWord::Unit(9)),
+    ("ten", NumberWord::Unit(10)),
+    ("eleven", NumberWord::Unit(11)),
+    ("twelve", NumberWord::Unit(12)),
+    ("thirteen", NumberWord::Unit(13)),
+    ("fourteen", NumberWord::Unit(14)),
+    ("fifteen", NumberWord::Unit(15)),
+    ("sixteen", NumberWord::Unit(16)),
+    ("seventeen", NumberWord::Unit(17)),
+    ("eighteen", NumberWord::Unit(18)),
+    ("nineteen", NumberWord::Unit(19)),
+    ("twenty", NumberWord::Unit(20)),
+    ("thirty", NumberWord::Unit(30)),
+    ("forty", NumberWord::Unit(40)),
+    ("fifty", NumberWord::Unit(50)),
+    ("sixty", NumberWord::Unit(60)),
+    ("seventy", NumberWord::Unit(70)),
+    ("eighty", NumberWord::Unit(80)),
+    ("ninety", NumberWord::Unit(90)),
+    ("hundred", NumberWord::Hundred),
+    ("thousand", NumberWord::Scale(1_000)),
+    ("million", NumberWord::Scale(1_000_000)),
+    ("billion", NumberWord::Scale(1_000_000_000)),
+    ("and", NumberWord::And),
+];
+
+/// Ordinaux anglais irréguliers et leur cardinal
+const ENGLISH_IRREGULAR_ORDINALS: &[(&str, &str)] = &[
+    ("first", "one"),
+    ("second", "two"),
+    ("third", "three"),
+    ("fifth", "five"),
+    ("eighth", "eight"),
+    ("ninth", "nine"),
+    ("twelfth", "twelve"),
+];
+
+/// Mots seuls gardés en lettres hors contexte (articles, adjectifs, durées)
+const FRENCH_AMBIGUOUS: &[&str] = &[
+    "un",
+    "une",
+    "neuf",
+    "premier",
+    "première",
+    "premiers",
+    "premières",
+];
+const ENGLISH_AMBIGUOUS: &[&str] = &["one", "first", "second"];
+
+const FRENCH_MONTHS: &[&str] = &[
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+const ENGLISH_MONTHS: &[&str] = &[
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// Monnaies (mot prononcé, symbole)
+const FRENCH_CURRENCIES: &[(&str, &str)] = &[
+    ("euro", "€"),
+    ("euros", "€"),
+    ("dollar", "$"),
+    ("dollars", "$"),
+];
+const ENGLISH_CURRENCIES: &[(&str, &str)] = &[
+    ("euro", "€"),
+    ("euros", "€"),
+    ("dollar", "$"),
+    ("dollars", "$"),
+    ("pound", "£"),
+    ("pounds", "£"),
+];
+
+impl Language {
+    fn from_code(code: Option<&str>) -> Option<Self> {
+        match code? {
+            "fr" => Some(Self::French),
+            "en" => Some(Self::English),
+            _ => None,
+        }
+    }
+
+    fn cardinal(self, word: &str) -> Option<NumberWord> {
+        let table = match self {
+            Self::French => FRENCH_WORDS,
+            Self::English => ENGLISH_WORDS,
+        };
+        table.iter().find(|(w, _)| *w == word).map(|(_, n)| *n)
+    }
+
+    /// Ordinal ("vingtième", "twentieth") → mot du cardinal et forme.
+    fn ordinal(self, word: &str) -> Option<(NumberWord, Ordinal)> {
+        let ordinal = Ordinal::default();
+        match self {
+            Self::French => {
+                if let Some(form) = word.strip_prefix("premi") {
+                    let (feminine, plural) = match form {
+                        "er" => (false, false),
+                        "ers" => (false, true),
+                        "ère" => (true, false),
+                        "ères" => (true, true),
+                        _ => return None,
+                    };
+                    let first = Ordinal {
+                        first: true,
+                        feminine,
+                        plural,
+                    };
+                    return Some((NumberWord::Unit(1), first));
+                }
+                let (stem, plural) = match word.strip_suffix("ièmes") {
+                    Some(stem) => (stem, true),
+                    None => (word.strip_suffix("ième")?, false),
+                };
+                let cardinal = match stem {
+                    "cinqu" => "cinq".to_string(),
+                    "neuv" => "neuf".to_string(),
+                    _ if self.cardinal(stem).is_some() => stem.to_string(),
+                    _ => format!("{}e", stem),
+                };
+                match self.cardinal(&cardinal)? {
+                    NumberWord::And => None,
+                    number => Some((number, Ordinal { plural, ..ordinal })),
+                }
+            }
+            Self::English => {
+                let cardinal = match ENGLISH_IRREGULAR_ORDINALS.iter().find(|(o, _)| *o == word) {
+                    Some((_, cardinal)) => cardinal.to_string(),
+                    None => match word.strip_suffix("ieth") {
+                        Some(stem) => format!("{}y", stem),
+                        None => word.strip_suffix("th")?.to_string(),
+                    },
+                };
+                match self.cardinal(&cardinal)? {
+                    NumberWord::And => None,
+                    number => Some((number, ordinal)),
+                }
+            }
+        }
+    }
+
+    fn is_ambiguous(self, word: &str) -> bool {
+        match self {
+            Self::French => FRENCH_AMBIGUOUS.contains(&word),
+            Self::English => ENGLISH_AMBIGUOUS.contains(&word),
+        }
+    }
+
+    /// Nom de mois; en anglais il doit porter sa majuscule ("May", pas "may").
+    fn is_month(self, token: &Token) -> bool {
+        match self {
+            Self::French => FRENCH_MONTHS.contains(&token.word.as_str()),
+            Self::English => {
+                ENGLISH_MONTHS.contains(&token.word.as_str())
+                    && token.text.starts_with(char::is_uppercase)
+            }
+        }
+    }
+
+    fn currency(self, word: &str) -> Option<&'static str> {
+        let table = match self {
+            Self::French => FRENCH_CURRENCIES,
+            Self::English => ENGLISH_CURRENCIES,
+        };
+        table.iter().find(|(w, _)| *w == word).map(|(_, s)| *s)
+    }
+
+    /// Écrit un entier, groupé par milliers à partir de 10 000.
+    fn format_integer(self, value: u64) -> String {
+        let digits = value.to_string();
+        if value < 10_000 {
+            return digits;
+        }
+        let separator = match self {
+            Self::French => ' ',
+            Self::English => ',',
+        };
+        let mut written = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, c) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                written.push(separator);
+            }
+            written.push(c);
+        }
+        written
+    }
+
+    fn format_ordinal(self, value: u64, ordinal: Ordinal) -> String {
+        let plural = if ordinal.plural { "s" } else { "" };
+        match self {
+            Self::French if ordinal.first && ordinal.feminine => format!("1re{}", plural),
+            Self::French if ordinal.first => format!("1er{}", plural),
+            Self::French => format!("{}e{}", value, plural),
+            Self::English => {
+                let suffix = match (value % 10, value % 100) {
+                    (_, 11..=13) => "th",
+                    (1, _) => "st",
+                    (2, _) => "nd",
+                    (3, _) => "rd",
+                    _ => "th",
+                };
+                format!("{}{}", value, suffix)
+            }
+        }
+    }
+}
+
+/// Mot du texte, découpé en ponctuation d'ouverture, mot et ponctuation finale.
+#[derive(Debug)]
+struct Token<'a> {
+    raw: &'a str,
+    lead: &'a str,
+    /// Mot tel qu'écrit, sans la ponctuation autour
+    text: &'a str,
+    punct: &'a str,
+    /// Forme comparable: minuscules, apostrophe droite
+    word: String,
+}
+
+impl<'a> Token<'a> {
+    fn new(raw: &'a str) -> Self {
+        let start = raw.find(char::is_alphanumeric).unwrap_or(raw.len());
+        let text = raw[start..].trim_end_matches(|c: char| !c.is_alphanumeric());
+        let end = start + text.len();
+        Self {
+            raw,
+            lead: &raw[..start],
+            text,
+            punct: &raw[end..],
+            word: text.replace('’', "'").to_lowercase(),
+        }
+    }
+
+    /// Le mot suivant peut prolonger l'expression (pas de ponctuation entre eux).
+    fn is_open(&self) -> bool {
+        self.punct.is_empty()
+    }
+}
+
+/// Cardinal en cours de lecture, mot après mot.
+#[derive(Debug, Clone, Default)]
+struct Cardinal {
+    /// Valeur des milliers, millions... déjà lus
+    total: u64,
+    /// Valeur sous le dernier multiplicateur (0 à 999)
+    current: u64,
+    last_scale: Option<u64>,
+    words: usize,
+    pending_and: bool,
+    /// "zéro" ne se combine avec rien
+    closed: bool,
+}
+
+impl Cardinal {
+    fn value(&self) -> u64 {
+        self.total + self.current
+    }
+
+    /// Ajoute un mot; `false` s'il ne peut pas prolonger le nombre.
+    fn push(&mut self, word: NumberWord, language: Language) -> bool {
+        if self.closed {
+            return false;
+        }
+        let french = language == Language::French;
+        let tail = self.current % 100;
+
+        match word {
+            NumberWord::And => {
+                let valid = match language {
+                    // vingt et un, soixante et onze
+                    Language::French => tail.is_multiple_of(10) && (20..=60).contains(&tail),
+                    // one hundred and five, two thousand and one
+                    Language::English => tail == 0,
+                };
+                if self.words == 0 || self.pending_and || !valid {
+                    return false;
+                }
+                self.pending_and = true;
+                return true;
+            }
+            NumberWord::Unit(0) => {
+                if self.words > 0 {
+                    return false;
+                }
+                self.closed = true;
+            }
+            NumberWord::Unit(n) => {
+                if self.pending_and && french && n != 1 && n != 11 {
+                    return false;
+                }
+                let valid = match n {
+                    // dix-sept, soixante-dix-sept; pas "ten seven"
+                    1..=9 => tail.is_multiple_of(10) && (french || tail != 10),
+                    // soixante-dix, quatre-vingt-douze
+                    10..=19 => tail == 0 || (french && (tail == 60 || tail == 80)),
+                    // quatre-vingt(s)
+                    20 if french && tail == 4 => {
+                        self.current += 76;
+                        self.pending_and = false;
+                        self.words += 1;
+                        return true;
+                    }
+                    _ => tail == 0,
+                };
+                if !valid {
+                    return false;
+                }
+                self.current += n;
+            }
+            NumberWord::Hundred => {
+                if self.pending_and || self.current >= 100 {
+                    return false;
+                }
+                self.current = self.current.max(1) * 100;
+            }
+            NumberWord::Scale(scale) => {
+                if self.pending_and || self.last_scale.is_some_and(|last| scale >= last) {
+                    return false;
+                }
+                self.total += self.current.max(1) * scale;
+                self.current = 0;
+                self.last_scale = Some(scale);
+            }
+        }
+
+        self.pending_and = false;
+        self.words += 1;
+        true
+    }
+}
+
+/// Nombre lu à partir d'un token.
+#[derive(Debug)]
+struct Number {
+    value: u64,
+    /// Index du premier token après le nombre
+    end: usize,
+    ordinal: Option<Ordinal>,
+    /// Mot seul qui n'est pas forcément un nombre ("un", "one")
+    ambiguous: bool,
+    /// Déjà écrit en chiffres ("23")
+    digits: bool,
+}
+
+/// Lit le plus long nombre commençant au token `start`.
+fn parse_number(tokens: &[Token], start: usize, language: Language) -> Option<Number> {
+    let first = tokens.get(start)?;
+    if !first.word.is_empty()
+        && first.word.len() <= 15
+        && first.word.chars().all(|c| c.is_ascii_digit())
+    {
+        return Some(Number {
+            value: first.word.parse().ok()?,
+            end: start + 1,
+            ordinal: None,
+            ambiguous: false,
+            digits: true,
+        });
+    }
+
+    let mut cardinal = Cardinal::default();
+    let mut best: Option<(u64, usize, Option<Ordinal>, usize)> = None;
+
+    for (k, token) in tokens.iter().enumerate().skip(start) {
+        if token.word.is_empty() || (k > start && !token.lead.is_empty()) {
+            break;
+        }
+
+        let mut next = cardinal.clone();
+        let mut ordinal = None;
+        let parts: Vec<&str> = token.word.split('-').collect();
+        let mut valid = true;
+        for (p, part) in parts.iter().enumerate() {
+            let word = match language.cardinal(part) {
+                Some(word) => word,
+                None if p + 1 == parts.len() => {
+                    let Some((word, form)) = language.ordinal(part) else {
+                        valid = false;
+                        break;
+                    };
+                    // "premier" ouvre un nombre, "unième" en termine un
+                    let placed = if form.first {
+                        next.words == 0
+                    } else {
+                        *part != "unième" || next.words > 0
+                    };
+                    if !placed {
+                        valid = false;
+                        break;
+                    }
+                    ordinal = Some(form);
+                    word
+                }
+                None => {
+                    valid = false;
+                    break;
+                }
+            };
+            if !next.push(word, language) {
+                valid = false;
+                break;
+            }
+        }
+        if !valid {
+            break;
+        }
+
+        cardinal = next;
+        if !cardinal.pending_and {
+            best = Some((cardinal.value(), k + 1, ordinal, cardinal.words));
+        }
+        if ordinal.is_some() || cardinal.closed || !token.is_open() {
+            break;
+        }
+    }
+
+    let (value, end, ordinal, words) = best?;
+    Some(Number {
+        value,
+        end,
+        ordinal,
+        ambiguous: words == 1 && language.is_ambiguous(&first.word),
+        digits: false,
+    })
+}
+
+/// Mot comparable du token `k`, vide après le dernier.
+fn word_at<'t>(tokens: &'t [Token], k: usize) -> &'t str {
+    tokens.get(k).map_or("", |t| t.word.as_str())
+}
+
+/// Les tokens à partir de `start` forment `phrase`, sans ponctuation entre eux.
+fn matches_phrase(tokens: &[Token], start: usize, phrase: &[&str]) -> bool {
+    phrase.iter().enumerate().all(|(n, word)| {
+        tokens.get(start + n).is_some_and(|t| {
+            t.word == *word && t.lead.is_empty() && (n + 1 == phrase.len() || t.is_open())
+        })
+    })
+}
+
+/// Texte écrit, avec la ponctuation autour de l'expression d'origine.
+fn finish(tokens: &[Token], start: usize, written: String, end: usize) -> (String, usize) {
+    (
+        format!("{}{}{}", tokens[start].lead, written, tokens[end - 1].punct),
+        end,
+    )
+}
+
+/// Heure française après "<n> heures": "3h30", "1h45", sinon "3 heures".
+fn french_time(tokens: &[Token], hours: u64, at: usize) -> Option<(String, usize)> {
+    let hour = tokens
+        .get(at)
+        .filter(|t| t.lead.is_empty() && matches!(t.word.as_str(), "heure" | "heures"))?;
+    if hours > 24 {
+        return None;
+    }
+    let clock = |h: u64, m: u64, end: usize| Some((format!("{}h{:02}", h, m), end));
+    let hour_before = (hours + 23) % 24;
+
+    if hour.is_open() {
+        let next = at + 1;
+        if matches_phrase(tokens, next, &["et", "quart"]) {
+            return clock(hours, 15, next + 2);
+        }
+        if matches_phrase(tokens, next, &["et", "demie"])
+            || matches_phrase(tokens, next, &["et", "demi"])
+        {
+            return clock(hours, 30, next + 2);
+        }
+        if matches_phrase(tokens, next, &["moins", "le", "quart"]) {
+            return clock(hour_before, 45, next + 3);
+        }
+        if matches_phrase(tokens, next, &["moins"]) && tokens[next].is_open() {
+            let minutes = parse_number(tokens, next + 1, Language::French)
+                .filter(|m| m.ordinal.is_none() && (1..=30).contains(&m.value));
+            if let Some(minutes) = minutes {
+                return clock(hour_before, 60 - minutes.value, minutes.end);
+            }
+        }
+        let minutes = parse_number(tokens, next, Language::French)
+            .filter(|m| m.ordinal.is_none() && (1..=59).contains(&m.value));
+        if let Some(minutes) = minutes {
+            // "trois heures vingt minutes" est une durée
+            let duration = tokens[minutes.end - 1].is_open()
+                && matches!(word_at(tokens, minutes.end), "minute" | "minutes");
+            if !duration {
+                return clock(hours, minutes.value, minutes.end);
+            }
+        }
+    }
+
+    Some((format!("{} {}", hours, hour.text), at + 1))
+}
+
+/// "am", "a.m.", "PM"...
+fn is_meridiem(token: &Token) -> bool {
+    token.lead.is_empty() && matches!(token.word.replace('.', "").as_str(), "am" | "pm")
+}
+
+/// Heure anglaise après "<n>": "7 o'clock", "3 pm", "3:30 pm", "3:05 am".
+fn english_time(tokens: &[Token], hours: u64, at: usize) -> Option<(String, usize)> {
+    let next = tokens.get(at).filter(|t| t.lead.is_empty())?;
+    if next.word == "o'clock" || is_meridiem(next) {
+        return Some((format!("{} {}", hours, next.text), at + 1));
+    }
+
+    let minutes = if next.word == "oh" && next.is_open() {
+        parse_number(tokens, at + 1, Language::English)
+            .filter(|m| m.ordinal.is_none() && (1..=9).contains(&m.value))
+    } else {
+        parse_number(tokens, at, Language::English)
+            .filter(|m| m.ordinal.is_none() && (10..=59).contains(&m.value))
+    }?;
+    let meridiem = tokens
+        .get(minutes.end)
+        .filter(|t| tokens[minutes.end - 1].is_open() && is_meridiem(t))?;
+
+    Some((
+        format!("{}:{:02} {}", hours, minutes.value, meridiem.text),
+        minutes.end + 1,
+    ))
+}
+
+/// "half past three", "quarter to five" → "3:30", "4:45".
+fn english_clock_phrase(tokens: &[Token], start: usize) -> Option<(String, usize)> {
+    let minutes = match word_at(tokens, start) {
+        "half" => 30,
+        "quarter" => 15,
+        _ => return None,
+    };
+    let past = match word_at(tokens, start + 1) {
+        "past" => true,
+        "to" if minutes == 15 => false,
+        _ => return None,
+    };
+    if !tokens[start].is_open()
+        || !tokens[start + 1].is_open()
+        || !tokens[start + 1].lead.is_empty()
+    {
+        return None;
+    }
+    let hour = parse_number(tokens, start + 2, Language::English)
+        .filter(|h| h.ordinal.is_none() && (1..=12).contains(&h.value))?;
+    let (hours, minutes) = if past {
+        (hour.value, minutes)
+    } else {
+        (
+            if hour.value == 1 { 12 } else { hour.value - 1 },
+            60 - minutes,
+        )
+    };
+
+    Some((format!("{}:{:02}", hours, minutes), hour.end))
+}
+
+/// Chiffres après la virgule ("zéro cinq" → "05").
+fn parse_fraction(tokens: &[Token], start: usize, language: Language) -> Option<(String, usize)> {
+    let zero = match language {
+        Language::French => "zéro",
+        Language::English => "zero",
+    };
+    let mut digits = String::new();
+    let mut k = start;
+    while matches_phrase(tokens, k, &[zero]) {
+        digits.push('0');
+        k += 1;
+        if !tokens[k - 1].is_open() {
+            return Some((digits, k));
+        }
+    }
+
+    let number = tokens
+        .get(k)
+        .filter(|t| t.lead.is_empty())
+        .and_then(|_| parse_number(tokens, k, language))
+        .filter(|n| n.ordinal.is_none());
+    match number {
+        Some(number) => {
+            digits.push_str(&number.value.to_string());
+            k = number.end;
+        }
+        None if digits.is_empty() => return None,
+        None => {}
+    }
+    Some((digits, k))
+}
+
+/// Fin de "pour cent" / "percent" à partir de `at`.
+fn percent_end(tokens: &[Token], at: usize, language: Language) -> Option<usize> {
+    let phrases: &[&[&str]] = match language {
+        Language::French => &[&["pour", "cent"], &["pourcent"], &["pour-cent"]],
+        Language::English => &[&["percent"], &["per", "cent"]],
+    };
+    phrases
+        .iter()
+        .find(|phrase| matches_phrase(tokens, at, phrase))
+        .map(|phrase| at + phrase.len())
+}
+
+/// Centimes après la monnaie: "cinquante", "et cinquante centimes", "and fifty cents".
+fn parse_cents(tokens: &[Token], at: usize, language: Language) -> Option<(u64, usize)> {
+    let (connector, units): (&str, &[&str]) = match language {
+        Language::French => ("et", &["centime", "centimes"]),
+        Language::English => ("and", &["cent", "cents", "penny", "pence"]),
+    };
+    let connected = matches_phrase(tokens, at, &[connector]) && tokens[at].is_open();
+    let start = if connected { at + 1 } else { at };
+    if !tokens.get(start)?.lead.is_empty() {
+        return None;
+    }
+
+    let cents = parse_number(tokens, start, language)
+        .filter(|c| c.ordinal.is_none() && (1..=99).contains(&c.value))?;
+    let has_unit = tokens[cents.end - 1].is_open()
+        && tokens.get(cents.end).is_some_and(|t| t.lead.is_empty())
+        && units.contains(&word_at(tokens, cents.end));
+    match (has_unit, connected) {
+        (true, _) => Some((cents.value, cents.end + 1)),
+        // "five dollars and three apples" n'a pas de centimes
+        (false, true) => None,
+        (false, false) => Some((cents.value, cents.end)),
+    }
+}
+
+/// Forme écrite de l'expression commençant au token `start`, et son token de fin.
+///
+/// `None` si le token ne commence pas un nombre, ou si le nombre doit rester
+/// tel quel (mot ambigu hors contexte, nombre déjà en chiffres).
+fn written_form(
+    tokens: &[Token],
+    start: usize,
+    language: Language,
+    after_month: bool,
+) -> Option<(String, usize)> {
+    if language == Language::English {
+        if let Some((written, end)) = english_clock_phrase(tokens, start) {
+            return Some(finish(tokens, start, written, end));
+        }
+    }
+
+    let number = parse_number(tokens, start, language)?;
+    let mut end = number.end;
+    let open = |end: usize| tokens[end - 1].is_open();
+    let month_at = |k: usize| {
+        open(k)
+            && tokens
+                .get(k)
+                .is_some_and(|t| t.lead.is_empty() && language.is_month(t))
+    };
+
+    if let Some(ordinal) = number.ordinal {
+        // "premier janvier", "May first", "the second of May"
+        let dated = match language {
+            Language::French => month_at(end),
+            Language::English => {
+                after_month
+                    || (matches_phrase(tokens, end, &["of"]) && open(end) && month_at(end + 1))
+            }
+        };
+        if number.ambiguous && !dated {
+            return None;
+        }
+        let written = language.format_ordinal(number.value, ordinal);
+        return Some(finish(tokens, start, written, end));
+    }
+
+    if open(end) {
+        let time = match language {
+            Language::French => french_time(tokens, number.value, end),
+            Language::English if (1..=12).contains(&number.value) => {
+                english_time(tokens, number.value, end)
+            }
+            Language::English => None,
+        };
+        if let Some((written, end)) = time {
+            return Some(finish(tokens, start, written, end));
+        }
+    }
+
+    let separator = match language {
+        Language::French => ',',
+        Language::English => '.',
+    };
+    let mut written = if number.digits {
+        tokens[start].text.to_string()
+    } else {
+        language.format_integer(number.value)
+    };
+    let mut integer = true;
+
+    let decimal_word = match language {
+        Language::French => "virgule",
+        Language::English => "point",
+    };
+    if open(end) && matches_phrase(tokens, end, &[decimal_word]) && tokens[end].is_open() {
+        if let Some((fraction, fraction_end)) = parse_fraction(tokens, end + 1, language) {
+            written = format!("{}{}{}", written, separator, fraction);
+            end = fraction_end;
+            integer = false;
+        }
+    }
+
+    if open(end) {
+        if let Some(percent_end) = percent_end(tokens, end, language) {
+            let written = match language {
+                Language::French => format!("{} %", written),
+                Language::English => format!("{}%", written),
+            };
+            return Some(finish(tokens, start, written, percent_end));
+        }
+
+        let symbol = tokens
+            .get(end)
+            .filter(|t| t.lead.is_empty())
+            .and_then(|t| language.currency(&t.word));
+        if let Some(symbol) = symbol {
+            let mut currency_end = end + 1;
+            if integer && open(currency_end) {
+                if let Some((cents, cents_end)) = parse_cents(tokens, currency_end, language) {
+                    written = format!("{}{}{:02}", written, separator, cents);
+                    currency_end = cents_end;
+                }
+            }
+            let written = match language {
+                Language::French => format!("{} {}", written, symbol),
+                Language::English => format!("{}{}", symbol, written),
+            };
+            return Some(finish(tokens, start, written, currency_end));
+        }
+
+        // Années anglaises: "nineteen eighty-four", "twenty oh five"
+        let year_start = language == Language::English
+            && integer
+            && !number.digits
+            && (after_month || (19..=20).contains(&number.value));
+        if year_start {
+            let tail = if matches_phrase(tokens, end, &["oh"]) && tokens[end].is_open() {
+                parse_number(tokens, end + 1, language)
+                    .filter(|t| !t.digits && t.ordinal.is_none() && (1..=9).contains(&t.value))
+            } else {
+                parse_number(tokens, end, language)
+                    .filter(|t| !t.digits && t.ordinal.is_none() && (10..=99).contains(&t.value))
+            };
+            if let Some(tail) = tail.filter(|_| (10..=99).contains(&number.value)) {
+                let written = format!("{}{:02}", number.value, tail.value);
+                return Some(finish(tokens, start, written, tail.end));
+            }
+        }
+    }
+
+    if number.digits && end == number.end {
+        return None;
+    }
+    let dated = integer
+        && (1..=31).contains(&number.value)
+        && match language {
+            Language::French => month_at(end),
+            Language::English => after_month,
+        };
+    if number.ambiguous && integer && !dated {
+        return None;
+    }
+    Some(finish(tokens, start, written, end))
+}
+
+/// Longueur de la phrase protégée commençant au token `start`, s'il y en a une.
+fn protected_at(tokens: &[Token], start: usize, protected: &[Vec<&str>]) -> Option<usize> {
+    protected
+        .iter()
+        .find(|phrase| matches_phrase(tokens, start, phrase))
+        .map(Vec::len)
+}
+
+/// Réécrit les nombres d'une ligne; la ligne est rendue telle quelle sans nombre.
+fn normalize_line(line: &str, language: Language, protected: &[Vec<&str>]) -> String {
+    let all_tokens: Vec<Token> = line.split_whitespace().map(Token::new).collect();
+    let mut words: Vec<String> = Vec::with_capacity(all_tokens.len());
+    let mut changed = false;
+    let mut after_month = false;
+    let mut i = 0;
+
+    while i < all_tokens.len() {
+        if let Some(len) = protected_at(&all_tokens, i, protected) {
+            words.extend(all_tokens[i..i + len].iter().map(|t| t.raw.to_string()));
+            after_month = false;
+            i += len;
+            continue;
+        }
+        // Un nombre s'arrête avant la phrase protégée suivante
+        let limit = (i + 1..all_tokens.len())
+            .find(|&k| protected_at(&all_tokens, k, protected).is_some())
+            .unwrap_or(all_tokens.len());
+        let tokens = &all_tokens[..limit];
+
+        // "a quarter past three" → "3:15"
+        let start = if language == Language::English
+            && tokens[i].word == "a"
+            && tokens[i].is_open()
+            && english_clock_phrase(tokens, i + 1).is_some()
+        {
+            i + 1
+        } else {
+            i
+        };
+
+        match written_form(tokens, start, language, after_month) {
+            Some((written, end)) => {
+                words.push(written);
+                changed = true;
+                after_month = false;
+                i = end;
+            }
+            None => {
+                after_month = language.is_month(&tokens[i]) && tokens[i].is_open();
+                words.push(tokens[i].raw.to_string());
+                i += 1;
+            }
+        }
+    }
+
+    if changed {
+        words.join(" ")
+    } else {
+        line.to_string()
+    }
+}
+
+/// Écrit en chiffres les nombres, dates et heures dictés.
+///
+/// Seuls le français et l'anglais sont pris en charge: le texte d'une autre
+/// langue (ou de langue inconnue) est rendu tel quel. Les phrases de
+/// `protected` (commandes de dictée, symboles du mode code) qui contiennent
+/// un nombre restent en lettres: "deux points" est un deux-points.
+pub fn normalize_numbers(text: &str, language: Option<&str>, protected: &[&str]) -> String {
+    let Some(language) = Language::from_code(language) else {
+        return text.to_string();
+    };
+    let is_number_word = |word: &str| {
+        word.split('-').any(|part| {
+            language
+                .cardinal(part)
+                .is_some_and(|n| n != NumberWord::And)
+        })
+    };
+    let protected: Vec<Vec<&str>> = protected
+        .iter()
+        .map(|phrase| phrase.split_whitespace().collect::<Vec<_>>())
+        .filter(|words| words.iter().any(|word| is_number_word(word)))
+        .collect();
+    text.lines()
+        .map(|line| normalize_line(line, language, &protected))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fr(text: &str) -> String {
+        normalize_numbers(text, Some("fr"), &[])
+    }
+
+    fn en(text: &str) -> String {
+        normalize_numbers(text, Some("en"), &[])
+    }
+
+    #[test]
+    fn test_french_cardinals() {
+        assert_eq!(
+            fr("Vingt-trois personnes et quatre-vingt-dix-sept chaises."),
+            "23 personnes et 97 chaises."
+        );
+        assert_eq!(fr("soixante et onze, vingt et un"), "71, 21");
+        assert_eq!(
+            fr("trois cent mille habitants en deux mille vingt-quatre"),
+            "300 000 habitants en 2024"
+        );
+        assert_eq!(fr("deux millions cinq cents"), "2 000 500");
+    }
+
+    #[test]
+    fn test_ambiguous_words_stay_outside_context() {
+        assert_eq!(fr("un livre neuf, une fois"), "un livre neuf, une fois");
+        assert_eq!(fr("le premier jour"), "le premier jour");
+        assert_eq!(en("one of them came first"), "one of them came first");
+        assert_eq!(fr("un euro et une heure"), "1 € et 1 heure");
+    }
+
+    #[test]
+    fn test_ordinals_and_dates() {
+        assert_eq!(
+            fr("le vingt et unième siècle, la deuxième fois"),
+            "le 21e siècle, la 2e fois"
+        );
+        assert_eq!(
+            fr("le premier janvier deux mille vingt-quatre"),
+            "le 1er janvier 2024"
+        );
+        assert_eq!(fr("la première mai"), "la 1re mai");
+        assert_eq!(
+            en("the twenty-first century, March third, nineteen eighty-four"),
+            "the 21st century, March 3rd, 1984"
+        );
+        assert_eq!(en("the second of May"), "the 2nd of May");
+        assert_eq!(en("we may one day"), "we may one day");
+        assert_eq!(en("in twenty oh five"), "in 2005");
+    }
+
+    #[test]
+    fn test_decimals_percentages_and_currencies() {
+        assert_eq!(fr("trois virgule cinq pour cent"), "3,5 %");
+        assert_eq!(fr("un virgule zéro cinq"), "1,05");
+        assert_eq!(fr("douze euros cinquante"), "12,50 €");
+        assert_eq!(fr("vingt-cinq mille dollars"), "25 000 $");
+        assert_eq!(en("three point five percent"), "3.5%");
+        assert_eq!(en("five dollars and fifty cents"), "$5.50");
+        assert_eq!(en("five dollars and three apples"), "$5 and 3 apples");
+        assert_eq!(en("one million pounds"), "£1,000,000");
+    }
+
+    #[test]
+    fn test_french_times() {
+        assert_eq!(fr("à trois heures et demie"), "à 3h30");
+        assert_eq!(fr("quinze heures trente."), "15h30.");
+        assert_eq!(fr("deux heures moins le quart"), "1h45");
+        assert_eq!(fr("vingt heures moins dix"), "19h50");
+        assert_eq!(fr("trois heures vingt minutes"), "3 heures 20 minutes");
+    }
+
+    #[test]
+    fn test_english_times() {
+        assert_eq!(en("at three thirty p.m."), "at 3:30 p.m.");
+        assert_eq!(en("seven o'clock, six am"), "7 o'clock, 6 am");
+        assert_eq!(en("a quarter past three"), "3:15");
+        assert_eq!(en("quarter to one"), "12:45");
+        assert_eq!(en("nine oh five am"), "9:05 am");
+    }
+
+    #[test]
+    fn test_protected_phrases_stay_in_words() {
+        let protected = ["deux points", "virgule", "double deux points"];
+        assert_eq!(
+            normalize_numbers(
+                "note deux points trois virgule cinq litres",
+                Some("fr"),
+                &protected
+            ),
+            "note deux points 3,5 litres"
+        );
+        assert_eq!(
+            normalize_numbers("vingt deux points", Some("fr"), &protected),
+            "20 deux points"
+        );
+        assert_eq!(fr("deux points"), "2 points");
+    }
+
+    #[test]
+    fn test_unsupported_language_and_plain_lines_unchanged() {
+        assert_eq!(
+            normalize_numbers("zwei und zwanzig", Some("de"), &[]),
+            "zwei und zwanzig"
+        );
+        assert_eq!(normalize_numbers("vingt", None, &[]), "vingt");
+        assert_eq!(fr("déjà  en 23 exemplaires"), "déjà  en 23 exemplaires");
+    }
+}
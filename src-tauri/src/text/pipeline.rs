@@ -0,0 +1,255 @@
+//! Pipeline module - post-processing chain for finished transcripts
+//!
+//! Runs after decoding (and after the hallucination filter), on the full
+//! text only: timed segments keep Whisper's raw wording, and the transcript
+//! keeps the raw text next to the processed one. Steps run in a fixed order,
+//! each one enabled by [postprocess] and the active profile; the user's
+//! replacement rules run last.
+
+use crate::config::loader::PostProcessConfig;
+use crate::text::rules::{self, CompiledRule};
+use crate::text::{cleanup, code, dictation, numbers, typography};
+use crate::transcription::transcript::Transcript;
+
+/// Langue des règles à appliquer: l'anglais après traduction, sinon la langue parlée.
+fn rules_language(transcript: &Transcript) -> Option<&str> {
+    if transcript.translated {
+        Some("en")
+    } else {
+        transcript.source_language.as_deref()
+    }
+}
+
+/// Applique la chaîne de post-traitement à un texte.
+///
+/// # Arguments
+/// * `text` - Texte brut
+/// * `config` - Étapes activées et listes de mots par langue
+/// * `language` - Code ISO 639-1 du texte, `None` si inconnu (règles sans liste de mots)
+/// * `replacements` - Règles du fichier de règles (compilées au chargement),
+///   appliquées en dernier
+pub fn process_text(
+    text: &str,
+    config: &PostProcessConfig,
+    language: Option<&str>,
+    replacements: &[CompiledRule],
+) -> String {
+    if !config.enabled {
+        return text.to_string();
+    }
+
+    let rules = config.rules(language);
+    let mut text = text.to_string();
+
+    if config.normalize_whitespace {
+        text = cleanup::normalize_whitespace(&text);
+    }
+    // Avant les commandes de dictée: "trois virgule cinq" est un nombre,
+    // mais "deux points" reste une commande
+    if config.normalize_numbers {
+        let mut protected: Vec<&str> = Vec::new();
+        if config.dictation_commands {
+            protected.extend(rules.commands.iter().map(|(phrase, _)| phrase.as_str()));
+        }
+        if config.code_dictation {
+            protected.extend(rules.symbols.iter().map(|(name, _)| name.as_str()));
+        }
+        text = numbers::normalize_numbers(&text, language, &protected);
+    }
+    // Avant les commandes de dictée: "point" est un symbole en mode code
+    if config.code_dictation {
+        text = code::apply_code_dictation(&text, &rules);
+    }
+    // Avant les autres étapes: les sauts de ligne dictés délimitent les phrases
+    if config.dictation_commands {
+        text = dictation::apply_commands(&text, &rules);
+    }
+    if config.remove_fillers {
+        text = cleanup::remove_fillers(&text, &rules);
+    }
+    if config.collapse_duplicates {
+        text = cleanup::collapse_duplicates(&text, &rules);
+    }
+    // En mode code, une majuscule changerait l'identifiant ("userId" → "UserId")
+    if config.fix_capitalization && !config.code_dictation {
+        text = cleanup::fix_capitalization(&text);
+    }
+    if config.french_typography && language == Some("fr") {
+        text = typography::apply_french_typography(&text);
+    }
+    // Règles de l'utilisateur en dernier: leur résultat n'est plus retouché
+    if config.apply_rules && !replacements.is_empty() {
+        text = rules::apply_rules(&text, replacements).0;
+    }
+
+    text
+}
+
+/// Remplace le texte d'un transcript par sa version post-traitée.
+///
+/// Le texte d'origine reste dans `raw_text`.
+pub fn process_transcript(
+    transcript: &mut Transcript,
+    config: &PostProcessConfig,
+    replacements: &[CompiledRule],
+) {
+    transcript.text = process_text(
+        &transcript.raw_text,
+        config,
+        rules_language(transcript),
+        replacements,
+    );
+}
+
+/// Post-traite un extrait du texte brut d'un transcript (la dictée qui suit
+/// une commande vocale), avec les règles de la langue du transcript.
+pub fn process_excerpt(
+    excerpt: &str,
+    transcript: &Transcript,
+    config: &PostProcessConfig,
+    replacements: &[CompiledRule],
+) -> String {
+    process_text(excerpt, config, rules_language(transcript), replacements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transcription::transcript::Segment;
+
+    fn transcript(text: &str, language: &str) -> Transcript {
+        let segment = Segment {
+            start_ms: 0,
+            end_ms: 1000,
+            text: text.to_string(),
+            no_speech_probability: 0.0,
+            words: Vec::new(),
+            alternatives: Vec::new(),
+            speaker: None,
+            speaker_turn: false,
+        };
+        let mut transcript = Transcript::from_segments(vec![segment], 1000);
+        transcript.source_language = Some(language.to_string());
+        transcript
+    }
+
+    /// Post-traitement activé, réglages par défaut
+    fn enabled() -> PostProcessConfig {
+        PostProcessConfig {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_process_transcript_keeps_raw_text() {
+        let raw = "euh le le rapport est prêt. on  l'envoie ?";
+        let mut transcript = transcript(raw, "fr");
+        process_transcript(&mut transcript, &enabled(), &[]);
+
+        assert_eq!(transcript.text, "Le rapport est prêt. On l'envoie ?");
+        assert_eq!(transcript.raw_text, raw);
+        assert_eq!(
+            transcript.segments[0].text, raw,
+            "Segments keep Whisper's wording"
+        );
+    }
+
+    #[test]
+    fn test_fillers_follow_language() {
+        let config = enabled();
+        assert_eq!(process_text("um, yes", &config, Some("en"), &[]), "Yes");
+        assert_eq!(process_text("um, yes", &config, Some("fr"), &[]), "Um, yes");
+        assert_eq!(process_text("um, yes", &config, None, &[]), "Um, yes");
+    }
+
+    #[test]
+    fn test_dictated_layout_is_capitalized() {
+        let config = PostProcessConfig {
+            dictation_commands: true,
+            ..enabled()
+        };
+        assert_eq!(
+            process_text(
+                "euh cher client virgule à la ligne merci point final",
+                &config,
+                Some("fr"),
+                &[]
+            ),
+            "Cher client,\nMerci."
+        );
+    }
+
+    #[test]
+    fn test_numbers_are_written_before_dictation_commands() {
+        let config = PostProcessConfig {
+            dictation_commands: true,
+            normalize_numbers: true,
+            ..enabled()
+        };
+        assert_eq!(
+            process_text(
+                "il reste trois virgule cinq litres virgule merci",
+                &config,
+                Some("fr"),
+                &[]
+            ),
+            "Il reste 3,5 litres, merci"
+        );
+        assert_eq!(
+            process_text(
+                "ordre du jour deux points budget à deux mille euros",
+                &config,
+                Some("fr"),
+                &[]
+            ),
+            "Ordre du jour: budget à 2000 €"
+        );
+
+        assert_eq!(
+            process_text("vingt-trois", &enabled(), Some("fr"), &[]),
+            "Vingt-trois"
+        );
+    }
+
+    #[test]
+    fn test_french_typography_only_applies_to_french() {
+        let config = PostProcessConfig {
+            french_typography: true,
+            ..enabled()
+        };
+        assert_eq!(
+            process_text("c'est prêt?", &config, Some("fr"), &[]),
+            "C’est prêt\u{202F}?"
+        );
+        assert_eq!(
+            process_text("it's ready?", &config, Some("en"), &[]),
+            "It's ready?"
+        );
+    }
+
+    #[test]
+    fn test_code_dictation_keeps_identifiers() {
+        let config = PostProcessConfig {
+            code_dictation: true,
+            ..enabled()
+        };
+        assert_eq!(
+            process_text("fix snake case user id, add test", &config, Some("en"), &[]),
+            "fix user_id, add test"
+        );
+        assert_eq!(
+            process_text("camel case user id. is null", &config, Some("en"), &[]),
+            "userId. is null"
+        );
+    }
+
+    #[test]
+    fn test_pipeline_is_opt_in() {
+        let config = PostProcessConfig::default();
+        assert_eq!(
+            process_text("euh  oui", &config, Some("fr"), &[]),
+            "euh  oui"
+        );
+    }
+}
@@ -0,0 +1,413 @@
+//! Rules module - user-defined text replacements and snippets
+//!
+//! A TOML rules file (rules.toml next to config.toml by default) lists
+//! literal or regex replacements applied to every transcript: snippets
+//! ("ma signature" → signature block) and recurring mishearings of names.
+//! The file is read once at startup, like the glossary.
+//!
+//! ```toml
+//! [[rule]]
+//! find = "ma signature"
+//! replace = "Cordialement,\nAmélie Martin"
+//!
+//! [[rule]]
+//! find = "jo[ae]quim"
+//! replace = "Joachim"
+//! regex = true
+//! ```
+
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use regex::{Captures, Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::config::loader::PostProcessConfig;
+use crate::error::AppError;
+
+/// Nom du fichier de règles par défaut, à côté de config.toml
+const DEFAULT_RULES_FILE: &str = "rules.toml";
+
+fn default_true() -> bool {
+    true
+}
+
+/// Règle de remplacement, appliquée dans l'ordre du fichier.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplacementRule {
+    /// Texte (ou motif regex) recherché, sans tenir compte de la casse
+    pub find: String,
+    /// Texte de remplacement (`$1`, `${nom}` pour les groupes d'une regex)
+    pub replace: String,
+    /// `find` est une expression régulière
+    #[serde(default)]
+    pub regex: bool,
+    /// Ne remplacer que des mots entiers ("Jo" ne touche pas "Joachim")
+    #[serde(default = "default_true")]
+    pub whole_word: bool,
+    /// Reporter la casse du texte trouvé sur le remplacement (MAJUSCULES, Initiale)
+    #[serde(default = "default_true")]
+    pub preserve_case: bool,
+}
+
+/// Contenu d'un fichier de règles.
+#[derive(Debug, Default, Deserialize)]
+struct RulesFile {
+    #[serde(default)]
+    rule: Vec<ReplacementRule>,
+}
+
+/// Règle prête à l'emploi: motif compilé.
+#[derive(Debug)]
+pub struct CompiledRule {
+    rule: ReplacementRule,
+    pattern: Regex,
+}
+
+impl CompiledRule {
+    /// Compile une règle.
+    ///
+    /// # Errors
+    /// - `ConfigurationError` si `find` est vide ou n'est pas une regex valide
+    pub fn new(rule: ReplacementRule) -> Result<Self, AppError> {
+        if rule.find.trim().is_empty() {
+            return Err(AppError::ConfigurationError(
+                "Règle de remplacement sans texte à rechercher".to_string(),
+            ));
+        }
+
+        let mut pattern = if rule.regex {
+            format!("(?:{})", rule.find)
+        } else {
+            regex::escape(&rule.find)
+        };
+        if rule.whole_word {
+            // \b seulement du côté d'un caractère de mot: "C++" reste trouvable
+            let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+            if rule.regex || is_word(rule.find.chars().next()) {
+                pattern = format!(r"\b{}", pattern);
+            }
+            if rule.regex || is_word(rule.find.chars().last()) {
+                pattern = format!(r"{}\b", pattern);
+            }
+        }
+
+        let pattern = RegexBuilder::new(&pattern)
+            .case_insensitive(true)
+            .build()
+            .map_err(|e| {
+                AppError::ConfigurationError(format!("Règle invalide '{}': {}", rule.find, e))
+            })?;
+
+        Ok(Self { rule, pattern })
+    }
+
+    /// Applique la règle; retourne le texte et le nombre de remplacements.
+    pub fn apply(&self, text: &str) -> (String, usize) {
+        let mut count = 0;
+        let result = self.pattern.replace_all(text, |caps: &Captures| {
+            count += 1;
+            let mut replacement = String::new();
+            if self.rule.regex {
+                caps.expand(&self.rule.replace, &mut replacement);
+            } else {
+                replacement.push_str(&self.rule.replace);
+            }
+            if self.rule.preserve_case {
+                replacement = match_case(&caps[0], &replacement);
+            }
+            replacement
+        });
+        (result.into_owned(), count)
+    }
+}
+
+/// Reporte la casse de `found` sur `replacement`.
+///
+/// Tout en majuscules → remplacement en majuscules; initiale majuscule →
+/// initiale du remplacement en majuscule; sinon le remplacement tel quel.
+fn match_case(found: &str, replacement: &str) -> String {
+    let letters: Vec<char> = found.chars().filter(|c| c.is_alphabetic()).collect();
+    let all_upper = letters.len() > 1 && letters.iter().all(|c| c.is_uppercase());
+
+    if all_upper {
+        return replacement.to_uppercase();
+    }
+    if letters.first().is_some_and(|c| c.is_uppercase()) {
+        let mut chars = replacement.chars();
+        if let Some(first) = chars.next() {
+            return first.to_uppercase().chain(chars).collect();
+        }
+    }
+    replacement.to_string()
+}
+
+/// Règles du fichier de règles, compilées une seule fois au chargement.
+///
+/// Partagées entre les requêtes (clone bon marché) et sérialisées comme la
+/// liste des règles dans les checkpoints: une règle invalide fait échouer la
+/// lecture, jamais le post-traitement.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(try_from = "Vec<ReplacementRule>", into = "Vec<ReplacementRule>")]
+pub struct RuleSet {
+    compiled: Arc<[CompiledRule]>,
+}
+
+impl RuleSet {
+    /// Compile des règles, dans l'ordre.
+    ///
+    /// # Errors
+    /// - `ConfigurationError` à la première règle invalide
+    pub fn new(rules: Vec<ReplacementRule>) -> Result<Self, AppError> {
+        let compiled = rules
+            .into_iter()
+            .map(CompiledRule::new)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            compiled: compiled.into(),
+        })
+    }
+
+    /// Règles compilées, à passer à `apply_rules`.
+    pub fn compiled(&self) -> &[CompiledRule] {
+        &self.compiled
+    }
+
+    /// Règles d'origine, dans l'ordre.
+    pub fn rules(&self) -> impl Iterator<Item = &ReplacementRule> {
+        self.compiled.iter().map(|compiled| &compiled.rule)
+    }
+
+    /// Aucune règle chargée.
+    pub fn is_empty(&self) -> bool {
+        self.compiled.is_empty()
+    }
+}
+
+impl PartialEq for RuleSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.rules().eq(other.rules())
+    }
+}
+
+impl TryFrom<Vec<ReplacementRule>> for RuleSet {
+    type Error = AppError;
+
+    fn try_from(rules: Vec<ReplacementRule>) -> Result<Self, AppError> {
+        Self::new(rules)
+    }
+}
+
+impl From<RuleSet> for Vec<ReplacementRule> {
+    fn from(set: RuleSet) -> Self {
+        set.rules().cloned().collect()
+    }
+}
+
+/// Compile des règles, dans l'ordre.
+///
+/// # Errors
+/// - `ConfigurationError` à la première règle invalide
+pub fn compile_rules(rules: &[ReplacementRule]) -> Result<Vec<CompiledRule>, AppError> {
+    rules.iter().cloned().map(CompiledRule::new).collect()
+}
+
+/// Applique les règles dans l'ordre; retourne le texte et le nombre de
+/// remplacements de chaque règle.
+pub fn apply_rules(text: &str, rules: &[CompiledRule]) -> (String, Vec<usize>) {
+    let mut text = text.to_string();
+    let mut counts = Vec::with_capacity(rules.len());
+    for rule in rules {
+        let (replaced, count) = rule.apply(&text);
+        text = replaced;
+        counts.push(count);
+    }
+    (text, counts)
+}
+
+/// Résultat d'un essai de règles sur un texte d'exemple.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RulesPreview {
+    /// Texte après application des règles
+    pub text: String,
+    /// Nombre de remplacements de chaque règle, dans l'ordre des règles
+    pub matches: Vec<usize>,
+}
+
+/// Essaie des règles compilées sur un texte, sans autre étape de post-traitement.
+pub fn preview(text: &str, rules: &[CompiledRule]) -> RulesPreview {
+    let (text, matches) = apply_rules(text, rules);
+    RulesPreview { text, matches }
+}
+
+/// Parse un fichier de règles et compile chaque règle.
+///
+/// # Errors
+/// - `ConfigurationError` si le TOML est invalide ou une règle incorrecte
+pub fn parse_rules(content: &str) -> Result<RuleSet, AppError> {
+    let file: RulesFile = toml::from_str(content)
+        .map_err(|e| AppError::ConfigurationError(format!("Fichier de règles invalide: {}", e)))?;
+    RuleSet::new(file.rule)
+}
+
+/// Charge le fichier de règles de remplacement, une fois au démarrage.
+///
+/// Sans `rules_file` configuré, rules.toml est cherché dans `config_dir`.
+/// Les interrupteurs `enabled` et `apply_rules` sont appliqués à la
+/// résolution de chaque requête (un profil peut les changer).
+///
+/// # Errors
+/// - `ConfigurationError` si le fichier configuré est illisible ou invalide
+pub fn load_rules(
+    config: &PostProcessConfig,
+    config_dir: Option<&Path>,
+) -> Result<RuleSet, AppError> {
+    let file = match config.rules_file {
+        Some(ref file) => Some(file.clone()),
+        None => config_dir
+            .map(|dir| dir.join(DEFAULT_RULES_FILE))
+            .filter(|path| path.exists()),
+    };
+
+    match file {
+        Some(path) => {
+            let content = fs::read_to_string(&path).map_err(|e| {
+                AppError::ConfigurationError(format!(
+                    "Fichier de règles illisible {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+            parse_rules(&content)
+        }
+        None => Ok(RuleSet::default()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn literal(find: &str, replace: &str) -> ReplacementRule {
+        ReplacementRule {
+            find: find.to_string(),
+            replace: replace.to_string(),
+            regex: false,
+            whole_word: true,
+            preserve_case: true,
+        }
+    }
+
+    fn apply(text: &str, rules: &[ReplacementRule]) -> String {
+        apply_rules(text, &compile_rules(rules).unwrap()).0
+    }
+
+    #[test]
+    fn test_parse_rules_file_with_defaults() {
+        let rules = parse_rules(
+            r#"
+            [[rule]]
+            find = "ma signature"
+            replace = "Cordialement,\nAmélie"
+
+            [[rule]]
+            find = "jo[ae]quim"
+            replace = "Joachim"
+            regex = true
+            preserve_case = false
+            "#,
+        )
+        .unwrap();
+        let rules: Vec<_> = rules.rules().collect();
+
+        assert_eq!(rules.len(), 2);
+        assert!(rules[0].whole_word && rules[0].preserve_case && !rules[0].regex);
+        assert!(rules[1].regex && !rules[1].preserve_case);
+    }
+
+    #[test]
+    fn test_invalid_rules_are_rejected() {
+        let result = parse_rules("[[rule]]\nfind = \"(\"\nreplace = \"\"\nregex = true");
+        assert!(matches!(result, Err(AppError::ConfigurationError(_))));
+
+        let result = parse_rules("[[rule]]\nfind = \" \"\nreplace = \"x\"");
+        assert!(matches!(result, Err(AppError::ConfigurationError(_))));
+    }
+
+    #[test]
+    fn test_snippet_expansion_preserves_case() {
+        let rules = [literal("ma signature", "cordialement,\nAmélie")];
+        assert_eq!(
+            apply("Merci. Ma signature", &rules),
+            "Merci. Cordialement,\nAmélie"
+        );
+        assert_eq!(apply("MA SIGNATURE", &rules), "CORDIALEMENT,\nAMÉLIE");
+    }
+
+    #[test]
+    fn test_whole_word_matching() {
+        let rules = [literal("jo", "Joe")];
+        assert_eq!(apply("Jo et Joachim", &rules), "Joe et Joachim");
+
+        let partial = ReplacementRule {
+            whole_word: false,
+            ..literal("jo", "Joe")
+        };
+        assert_eq!(apply("Joachim", &[partial]), "Joeachim");
+
+        assert_eq!(
+            apply("du C++ moderne", &[literal("C++", "C++20")]),
+            "du C++20 moderne"
+        );
+    }
+
+    #[test]
+    fn test_regex_rules_expand_groups_and_report_counts() {
+        let rule = ReplacementRule {
+            regex: true,
+            ..literal(r"jo[ae]quim (\w+)", "Joachim $1")
+        };
+        let (text, counts) = apply_rules(
+            "joaquim martin et Joequim Durand",
+            &compile_rules(&[rule, literal("absent", "x")]).unwrap(),
+        );
+        assert_eq!(text, "Joachim martin et Joachim Durand");
+        assert_eq!(counts, vec![2, 0]);
+    }
+
+    #[test]
+    fn test_load_default_file_from_config_dir() {
+        let dir =
+            std::env::temp_dir().join(format!("vocal-note-taker-rules-dir-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join(DEFAULT_RULES_FILE),
+            "[[rule]]\nfind = \"jo\"\nreplace = \"Joe\"",
+        )
+        .unwrap();
+
+        let rules = load_rules(&PostProcessConfig::default(), Some(&dir));
+        let without_dir = load_rules(&PostProcessConfig::default(), None);
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(
+            rules.unwrap(),
+            RuleSet::new(vec![literal("jo", "Joe")]).unwrap()
+        );
+        assert!(without_dir.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_rule_set_round_trips_through_checkpoints() {
+        let rules = RuleSet::new(vec![literal("jo", "Joe")]).unwrap();
+        let json = serde_json::to_string(&rules).unwrap();
+        let restored: RuleSet = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, rules);
+        assert_eq!(apply_rules("jo", restored.compiled()).0, "Joe");
+
+        // Règle modifiée à la main: la lecture échoue, pas le post-traitement
+        let invalid = json.replace("\"jo\"", "\" \"");
+        assert!(serde_json::from_str::<RuleSet>(&invalid).is_err());
+    }
+}
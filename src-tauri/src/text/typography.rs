@@ -0,0 +1,250 @@
+//! Typography module - French typographic rules
+//!
+//! Whisper writes French with English typography: "Quoi?", "l'homme",
+//! "...". This step adds narrow no-break spaces before ; : ! ? » and after «,
+//! turns straight quotes and apostrophes into « » and ’, and writes "..." as
+//! an ellipsis. Code between backticks, URLs, e-mail addresses and
+//! code-looking words are left as they are (the punctuation around them is
+//! still spaced), and so are numbers: "3:30" or "0.5" have no space to add.
+
+/// Espace fine insécable (U+202F)
+const NARROW_NBSP: char = '\u{202F}';
+
+/// Ponctuation précédée d'une espace fine
+const SPACED_BEFORE: &[char] = &[';', ':', '!', '?', '»'];
+
+/// Signes qui ne se séparent pas entre eux ("?!", "!»")
+const DOUBLE_PUNCTUATION: &[char] = &[';', ':', '!', '?', '»', '…', '.', ','];
+
+/// Ponctuation pouvant précéder un mot protégé sans en faire partie
+const LEADING_PUNCTUATION: &[char] = &['«', '"', '“'];
+
+/// Ponctuation pouvant suivre un mot protégé sans en faire partie
+const TRAILING_PUNCTUATION: &[char] = &[';', ':', '!', '?', '»', '…', '.', ',', '"', '”'];
+
+/// Morceau de ligne: mot ou espaces.
+#[derive(Debug)]
+enum Piece {
+    Word { text: String, protected: bool },
+    Space(String),
+}
+
+/// Mot à ne pas retoucher: adresse, e-mail, code.
+fn is_protected(word: &str) -> bool {
+    word.contains("://")
+        || word.starts_with("www.")
+        || (word.contains('@') && word.contains('.'))
+        || word.contains("::")
+        || word.contains("()")
+        || word.contains("->")
+        || word.contains(['{', '}', '<', '>', '=', '\\', '_', '|'])
+}
+
+/// Mot protégé: seule la ponctuation autour est retouchée ("jo@exemple.fr !").
+fn typeset_protected(word: &str, in_quote: &mut bool) -> String {
+    let start = word.len() - word.trim_start_matches(LEADING_PUNCTUATION).len();
+    let end = word.trim_end_matches(TRAILING_PUNCTUATION).len();
+    if start >= end || !is_protected(&word[start..end]) {
+        return word.to_string();
+    }
+
+    let mut result = replace_signs(&word[..start], in_quote);
+    if result.ends_with('«') {
+        result.push(NARROW_NBSP);
+    }
+    result.push_str(&word[start..end]);
+    let trailing = space_inside(&replace_signs(&word[end..], in_quote));
+    if trailing.starts_with(SPACED_BEFORE) {
+        result.push(NARROW_NBSP);
+    }
+    result.push_str(&trailing);
+    result
+}
+
+/// Apostrophes, guillemets et points de suspension d'un mot.
+fn replace_signs(word: &str, in_quote: &mut bool) -> String {
+    let chars: Vec<char> = word.chars().collect();
+    let mut result = String::with_capacity(word.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let previous_letter = i > 0 && chars[i - 1].is_alphabetic();
+        let next_letter = chars.get(i + 1).is_some_and(|n| n.is_alphabetic());
+        match c {
+            '\'' if previous_letter && next_letter => result.push('’'),
+            '"' => {
+                result.push(if *in_quote { '»' } else { '«' });
+                *in_quote = !*in_quote;
+            }
+            '“' => result.push('«'),
+            '”' => result.push('»'),
+            '.' if chars.get(i + 1) == Some(&'.') && chars.get(i + 2) == Some(&'.') => {
+                result.push('…');
+                i += 2;
+            }
+            _ => result.push(c),
+        }
+        i += 1;
+    }
+    result
+}
+
+/// Espaces fines à l'intérieur d'un mot ("Quoi?" → "Quoi ?", "«oui»").
+///
+/// Un deux-points suivi d'un autre caractère ("3:30", "http:") n'est pas
+/// une ponctuation: l'espace n'est ajoutée qu'en fin de mot.
+fn space_inside(word: &str) -> String {
+    let chars: Vec<char> = word.chars().collect();
+    let mut result = String::with_capacity(word.len() + 6);
+
+    for (i, &c) in chars.iter().enumerate() {
+        let previous = i.checked_sub(1).map(|p| chars[p]);
+        let next = chars.get(i + 1).copied();
+
+        let closes_word = next.is_none_or(|n| DOUBLE_PUNCTUATION.contains(&n));
+        let needs_space = SPACED_BEFORE.contains(&c)
+            && (c == '»' || closes_word)
+            && previous.is_some_and(|p| {
+                !p.is_whitespace() && !DOUBLE_PUNCTUATION.contains(&p) && p != '«'
+            });
+        if needs_space {
+            result.push(NARROW_NBSP);
+        }
+        result.push(c);
+        if c == '«' && next.is_some_and(|n| !n.is_whitespace() && n != '»') {
+            result.push(NARROW_NBSP);
+        }
+    }
+    result
+}
+
+/// Applique les règles au texte hors code d'une ligne.
+fn typeset(text: &str, in_quote: &mut bool) -> String {
+    let mut pieces: Vec<Piece> = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let first_is_space = rest.starts_with(char::is_whitespace);
+        let end = rest
+            .find(|c: char| c.is_whitespace() != first_is_space)
+            .unwrap_or(rest.len());
+        let (piece, tail) = rest.split_at(end);
+        pieces.push(if first_is_space {
+            Piece::Space(piece.to_string())
+        } else if is_protected(piece) {
+            Piece::Word {
+                text: typeset_protected(piece, in_quote),
+                protected: true,
+            }
+        } else {
+            Piece::Word {
+                text: space_inside(&replace_signs(piece, in_quote)),
+                protected: false,
+            }
+        });
+        rest = tail;
+    }
+
+    // Espaces entre un mot et une ponctuation séparée ("quoi ?", "« oui »")
+    for i in 0..pieces.len() {
+        if !matches!(pieces[i], Piece::Space(_)) {
+            continue;
+        }
+        let opens_before = match i.checked_sub(1).map(|p| &pieces[p]) {
+            Some(Piece::Word {
+                text,
+                protected: false,
+            }) => text.ends_with('«'),
+            _ => false,
+        };
+        let closes_after = match pieces.get(i + 1) {
+            Some(Piece::Word {
+                text,
+                protected: false,
+            }) => text.starts_with(SPACED_BEFORE) && i > 0,
+            _ => false,
+        };
+        if opens_before || closes_after {
+            pieces[i] = Piece::Space(NARROW_NBSP.to_string());
+        }
+    }
+
+    pieces
+        .into_iter()
+        .map(|piece| match piece {
+            Piece::Word { text, .. } | Piece::Space(text) => text,
+        })
+        .collect()
+}
+
+/// Applique la typographie française, ligne par ligne.
+///
+/// Le code entre accents graves est recopié tel quel; un guillemet droit
+/// ouvert sur une ligne se ferme sur la même ligne.
+pub fn apply_french_typography(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            let mut in_quote = false;
+            line.split('`')
+                .enumerate()
+                .map(|(n, part)| {
+                    if n % 2 == 1 {
+                        part.to_string()
+                    } else {
+                        typeset(part, &mut in_quote)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("`")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Écrit les espaces fines comme "_" pour des assertions lisibles.
+    fn french(text: &str) -> String {
+        apply_french_typography(text).replace(NARROW_NBSP, "_")
+    }
+
+    #[test]
+    fn test_narrow_spaces_before_double_punctuation() {
+        assert_eq!(
+            french("Quoi? Vraiment ! Note: la suite; fin?!"),
+            "Quoi_? Vraiment_! Note_: la suite_; fin_?!"
+        );
+    }
+
+    #[test]
+    fn test_guillemets_and_apostrophes() {
+        assert_eq!(
+            french("Il a dit \"l'homme est là\" puis «oui» et “non”."),
+            "Il a dit «_l’homme est là_» puis «_oui_» et «_non_»."
+        );
+        assert_eq!(french("« déjà espacé »"), "«_déjà espacé_»");
+    }
+
+    #[test]
+    fn test_ellipsis() {
+        assert_eq!(french("Eh bien... voilà"), "Eh bien… voilà");
+    }
+
+    #[test]
+    fn test_urls_code_and_numbers_untouched() {
+        assert_eq!(
+            french("Voir https://exemple.fr/a?b=c ou `std::env::args()?` à 3:30, 0.5 mg"),
+            "Voir https://exemple.fr/a?b=c ou `std::env::args()?` à 3:30, 0.5 mg"
+        );
+        assert_eq!(
+            french("Écrire à jo@exemple.fr!"),
+            "Écrire à jo@exemple.fr_!"
+        );
+        assert_eq!(
+            french("Voir \"https://exemple.fr\"."),
+            "Voir «_https://exemple.fr_»."
+        );
+    }
+}
@@ -0,0 +1,301 @@
+//! Benchmark module - local model and thread-count comparison
+//!
+//! Transcribes the same sample with each installed model and thread count,
+//! so everyone can pick the fastest setup that keeps up on their own CPU.
+//! Runs entirely offline (NFR-SEC-1).
+
+use std::f32::consts::PI;
+use std::path::Path;
+use std::time::Instant;
+
+use crate::error::AppError;
+use crate::transcription::models::ModelInfo;
+use crate::transcription::options::TranscriptionOptions;
+use crate::transcription::whisper::{
+    read_wav_samples, transcribe_samples, validate_wav_file, WhisperModel,
+};
+
+/// Fréquence d'échantillonnage attendue par Whisper
+const SAMPLE_RATE: usize = 16_000;
+
+/// Durée de l'échantillon généré (secondes)
+pub const GENERATED_SAMPLE_SECS: usize = 30;
+
+/// Résultat pour un couple modèle / nombre de threads.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BenchmarkResult {
+    /// Nom du fichier modèle
+    pub model: String,
+    pub n_threads: usize,
+    /// Temps de chargement du modèle (identique pour tous les threads d'un modèle)
+    pub load_ms: u64,
+    /// Temps de transcription de l'échantillon
+    pub decode_ms: u64,
+    /// Durée de l'échantillon
+    pub audio_ms: u64,
+    /// decode_ms / audio_ms: < 1.0 = plus rapide que le temps réel
+    pub real_time_factor: f64,
+    /// Message d'erreur si le chargement ou la transcription a échoué (mesures à 0)
+    pub error: Option<String>,
+}
+
+/// Charge l'échantillon de benchmark: fichier WAV fourni, sinon signal généré.
+///
+/// Un enregistrement réel donne des temps plus représentatifs: sur un signal
+/// synthétique Whisper produit peu de texte et le décodage est plus court.
+///
+/// # Errors
+/// - `InvalidAudioFormat` si le WAV fourni n'est pas 16kHz mono
+/// - `TranscriptionFailed` si le WAV fourni est illisible
+pub fn load_sample(path: Option<&Path>) -> Result<Vec<f32>, AppError> {
+    match path {
+        Some(path) => {
+            validate_wav_file(path)?;
+            read_wav_samples(path)
+        }
+        None => Ok(generate_sample(GENERATED_SAMPLE_SECS)),
+    }
+}
+
+/// Génère un signal proche de la parole: voyelles harmoniques modulées en
+/// syllabes (~4 par seconde) séparées de courts silences.
+pub fn generate_sample(duration_secs: usize) -> Vec<f32> {
+    let total = duration_secs * SAMPLE_RATE;
+    // Quelques formants de voyelles (Hz), parcourus de syllabe en syllabe
+    let vowels: [(f32, f32); 4] = [
+        (700.0, 1200.0),
+        (300.0, 2300.0),
+        (500.0, 900.0),
+        (400.0, 2000.0),
+    ];
+    let syllable_len = SAMPLE_RATE / 4;
+
+    (0..total)
+        .map(|i| {
+            let t = i as f32 / SAMPLE_RATE as f32;
+            let syllable = i / syllable_len;
+            let position = (i % syllable_len) as f32 / syllable_len as f32;
+
+            // Une syllabe sur 8 est muette (pause entre les mots)
+            if syllable % 8 == 7 {
+                return 0.0;
+            }
+
+            let (f1, f2) = vowels[syllable % vowels.len()];
+            // Fondamentale légèrement variable, comme une intonation
+            let pitch = 120.0 + 20.0 * (2.0 * PI * 0.5 * t).sin();
+            let envelope = (PI * position).sin();
+
+            let voice = (2.0 * PI * pitch * t).sin()
+                + 0.5 * (2.0 * PI * f1 * t).sin()
+                + 0.25 * (2.0 * PI * f2 * t).sin();
+            0.2 * envelope * voice
+        })
+        .collect()
+}
+
+/// Nombres de threads essayés par défaut: puissances de 2 jusqu'au nombre de cœurs.
+pub fn default_thread_counts() -> Vec<usize> {
+    let available = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+    thread_counts_up_to(available)
+}
+
+fn thread_counts_up_to(available: usize) -> Vec<usize> {
+    let mut counts: Vec<usize> = std::iter::successors(Some(1), |n| Some(n * 2))
+        .take_while(|n| *n <= available)
+        .collect();
+    if counts.last() != Some(&available) {
+        counts.push(available);
+    }
+    counts
+}
+
+/// Mesure chaque modèle avec chaque nombre de threads.
+///
+/// Les modèles sont chargés un par un et libérés après mesure, pour ne jamais
+/// en garder deux en mémoire.
+///
+/// # Arguments
+/// * `models` - Modèles à comparer
+/// * `samples` - Échantillon 16kHz mono
+/// * `thread_counts` - Nombres de threads à essayer
+/// * `options` - Paramètres de transcription (n_threads est remplacé)
+/// * `on_result` - Appelé après chaque mesure, avec l'index de la mesure et le total
+///
+/// Un modèle qui ne se charge pas ou une transcription qui échoue donne un
+/// résultat portant l'erreur; les autres mesures continuent.
+///
+/// # Errors
+/// - `ConfigurationError` si aucun modèle ou aucun nombre de threads n'est fourni
+pub fn run_benchmark<F>(
+    models: &[ModelInfo],
+    samples: &[f32],
+    thread_counts: &[usize],
+    options: &TranscriptionOptions,
+    mut on_result: F,
+) -> Result<Vec<BenchmarkResult>, AppError>
+where
+    F: FnMut(&BenchmarkResult, usize, usize),
+{
+    if models.is_empty() {
+        return Err(AppError::ConfigurationError(
+            "Aucun modèle installé à comparer".to_string(),
+        ));
+    }
+    if thread_counts.is_empty() || thread_counts.contains(&0) {
+        return Err(AppError::ConfigurationError(
+            "Nombres de threads invalides: au moins une valeur ≥ 1 attendue".to_string(),
+        ));
+    }
+
+    let audio_ms = (samples.len() * 1000 / SAMPLE_RATE) as u64;
+    let total = models.len() * thread_counts.len();
+    let mut results = Vec::with_capacity(total);
+
+    for info in models {
+        let started = Instant::now();
+        let model = WhisperModel::load(&info.path);
+        let load_ms = started.elapsed().as_millis() as u64;
+
+        for &n_threads in thread_counts {
+            let mut result = BenchmarkResult {
+                model: info.name.clone(),
+                n_threads,
+                load_ms,
+                decode_ms: 0,
+                audio_ms,
+                real_time_factor: 0.0,
+                error: None,
+            };
+
+            let options = TranscriptionOptions {
+                n_threads: Some(n_threads),
+                ..options.clone()
+            };
+            let started = Instant::now();
+            let decoded = model
+                .as_ref()
+                .map_err(Clone::clone)
+                .and_then(|model| transcribe_samples(model, samples, &options, None));
+
+            match decoded {
+                Ok(_) => {
+                    result.decode_ms = started.elapsed().as_millis() as u64;
+                    result.real_time_factor = real_time_factor(result.decode_ms, audio_ms);
+                    println!(
+                        "Benchmark {} ({} threads): load {} ms, decode {} ms, RTF {:.2}",
+                        result.model, n_threads, load_ms, result.decode_ms, result.real_time_factor
+                    );
+                }
+                Err(e) => {
+                    eprintln!(
+                        "Benchmark {} ({} threads) failed: {}",
+                        result.model, n_threads, e
+                    );
+                    result.error = Some(e.to_string());
+                }
+            }
+            on_result(&result, results.len() + 1, total);
+            results.push(result);
+        }
+    }
+
+    Ok(results)
+}
+
+/// Facteur temps réel: temps de calcul / durée de l'audio.
+fn real_time_factor(decode_ms: u64, audio_ms: u64) -> f64 {
+    if audio_ms == 0 {
+        return 0.0;
+    }
+    decode_ms as f64 / audio_ms as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_sample_length_and_range() {
+        let samples = generate_sample(2);
+        assert_eq!(samples.len(), 2 * SAMPLE_RATE);
+        assert!(samples.iter().all(|s| (-1.0..=1.0).contains(s)));
+        assert!(
+            samples.iter().any(|s| s.abs() > 0.1),
+            "Sample should not be silent"
+        );
+    }
+
+    #[test]
+    fn test_generated_sample_has_pauses() {
+        let samples = generate_sample(2);
+        let syllable_len = SAMPLE_RATE / 4;
+        let pause = &samples[7 * syllable_len..8 * syllable_len];
+        assert!(pause.iter().all(|s| *s == 0.0));
+    }
+
+    #[test]
+    fn test_thread_counts_up_to() {
+        assert_eq!(thread_counts_up_to(1), vec![1]);
+        assert_eq!(thread_counts_up_to(8), vec![1, 2, 4, 8]);
+        assert_eq!(thread_counts_up_to(6), vec![1, 2, 4, 6]);
+    }
+
+    #[test]
+    fn test_real_time_factor() {
+        assert_eq!(real_time_factor(15_000, 30_000), 0.5);
+        assert_eq!(real_time_factor(100, 0), 0.0);
+    }
+
+    #[test]
+    fn test_run_benchmark_requires_models_and_threads() {
+        let options = TranscriptionOptions::resolve(
+            &crate::config::AppConfig::default(),
+            &Default::default(),
+            &Default::default(),
+        )
+        .unwrap();
+
+        let result = run_benchmark(&[], &[], &[4], &options, |_, _, _| {});
+        assert!(matches!(result, Err(AppError::ConfigurationError(_))));
+
+        let model = ModelInfo {
+            name: "ggml-tiny.bin".to_string(),
+            path: "/nonexistent/ggml-tiny.bin".into(),
+            size_bytes: 0,
+        };
+        let result = run_benchmark(&[model], &[], &[0], &options, |_, _, _| {});
+        assert!(matches!(result, Err(AppError::ConfigurationError(_))));
+    }
+
+    #[test]
+    fn test_failed_model_is_reported_and_benchmark_continues() {
+        let options = TranscriptionOptions::resolve(
+            &crate::config::AppConfig::default(),
+            &Default::default(),
+            &Default::default(),
+        )
+        .unwrap();
+        let missing = |name: &str| ModelInfo {
+            name: name.to_string(),
+            path: format!("/nonexistent/{}", name).into(),
+            size_bytes: 0,
+        };
+        let models = [missing("ggml-tiny.bin"), missing("ggml-base.bin")];
+
+        let mut reported = Vec::new();
+        let results = run_benchmark(&models, &[], &[1, 2], &options, |result, index, total| {
+            reported.push((result.model.clone(), index, total));
+        })
+        .unwrap();
+
+        assert_eq!(results.len(), 4);
+        assert!(results
+            .iter()
+            .all(|r| r.error.is_some() && r.decode_ms == 0));
+        assert_eq!(results[3].model, "ggml-base.bin");
+        assert_eq!(reported.last(), Some(&("ggml-base.bin".to_string(), 4, 4)));
+    }
+}
@@ -0,0 +1,530 @@
+//! Chunking module - long recordings transcribed window by window
+//!
+//! Recordings longer than [transcription.chunking] threshold_secs are split
+//! into windows cut at the quietest point near each boundary, with a short
+//! overlap for context. Only one window is in memory at a time, and a
+//! checkpoint is written next to the recording after each window so that a
+//! crash or a shutdown resumes where it stopped (not for in-memory
+//! recordings, which have nothing left to resume from). A job cancelled by
+//! the user deletes its checkpoint with its audio.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::loader::ChunkingConfig;
+use crate::error::AppError;
+use crate::transcription::filter::RemovedSegment;
+use crate::transcription::options::TranscriptionOptions;
+use crate::transcription::queue::CancelFlag;
+use crate::transcription::transcript::{continue_speakers, DetectedLanguage, Segment, Transcript};
+use crate::transcription::whisper::{
+    read_wav_range, transcribe_samples, validate_wav_file, wav_sample_count, WhisperModel,
+    TRANSCRIPTION_CANCELLED,
+};
+
+/// Échantillons par seconde (Whisper travaille en 16kHz)
+const SAMPLES_PER_SEC: usize = 16_000;
+
+/// Trame d'analyse d'énergie pour la recherche de silence (100 ms)
+const SILENCE_FRAME: usize = SAMPLES_PER_SEC / 10;
+
+/// Suffixe du checkpoint, à côté du WAV (recording-….checkpoint.json)
+const CHECKPOINT_EXTENSION: &str = "checkpoint.json";
+
+/// Fenêtre de l'audio, en échantillons.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChunkSpan {
+    pub index: usize,
+    /// Début de la fenêtre (recouvrement inclus)
+    pub start: usize,
+    /// Fin de la fenêtre (point de coupure)
+    pub end: usize,
+    /// Début de la partie propre à cette fenêtre: les segments antérieurs
+    /// appartiennent déjà à la fenêtre précédente
+    pub keep_from: usize,
+}
+
+/// Fenêtre transcrite, transmise au fur et à mesure.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChunkResult {
+    pub index: usize,
+    pub total: usize,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub text: String,
+    /// Segments horodatés depuis le début de l'enregistrement
+    pub segments: Vec<Segment>,
+}
+
+/// État sauvegardé après chaque fenêtre.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// Nombre d'échantillons du WAV, pour ne reprendre que le même enregistrement
+    pub total_samples: usize,
+    /// Paramètres de la transcription d'origine
+    pub options: TranscriptionOptions,
+    pub spans: Vec<ChunkSpan>,
+    /// Langue détectée sur la première fenêtre, imposée aux suivantes
+    pub detected_language: Option<DetectedLanguage>,
+    /// Segments des fenêtres terminées, dans l'ordre
+    pub completed: Vec<Vec<Segment>>,
+    /// Segments retirés par le filtre d'hallucinations
+    #[serde(default)]
+    pub removed: Vec<RemovedSegment>,
+}
+
+impl Checkpoint {
+    /// Charge le checkpoint d'un enregistrement, s'il existe et est lisible.
+    pub fn load(audio_path: &Path) -> Option<Self> {
+        let content = fs::read_to_string(checkpoint_path(audio_path)).ok()?;
+        match serde_json::from_str(&content) {
+            Ok(checkpoint) => Some(checkpoint),
+            Err(e) => {
+                eprintln!("Warning: Ignoring unreadable checkpoint: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Écrit le checkpoint (fichier temporaire renommé, jamais de checkpoint tronqué).
+    ///
+    /// # Errors
+    /// - `IoError` si l'écriture échoue
+    pub fn save(&self, audio_path: &Path) -> Result<(), AppError> {
+        let path = checkpoint_path(audio_path);
+        let tmp_path = checkpoint_tmp_path(audio_path);
+        let content = serde_json::to_string(self)
+            .map_err(|e| AppError::IoError(format!("Cannot serialize checkpoint: {}", e)))?;
+        fs::write(&tmp_path, content)?;
+        fs::rename(&tmp_path, &path)?;
+        Ok(())
+    }
+}
+
+/// Chemin du checkpoint associé à un enregistrement.
+pub fn checkpoint_path(audio_path: &Path) -> PathBuf {
+    audio_path.with_extension(CHECKPOINT_EXTENSION)
+}
+
+/// Fichier temporaire écrit avant d'être renommé en checkpoint (recording-….checkpoint.tmp).
+pub fn checkpoint_tmp_path(audio_path: &Path) -> PathBuf {
+    checkpoint_path(audio_path).with_extension("tmp")
+}
+
+/// Supprime le checkpoint d'un enregistrement (transcription terminée ou abandonnée),
+/// ainsi qu'un éventuel checkpoint en cours d'écriture.
+pub fn remove_checkpoint(audio_path: &Path) {
+    for path in [checkpoint_path(audio_path), checkpoint_tmp_path(audio_path)] {
+        if path.exists() {
+            if let Err(e) = fs::remove_file(&path) {
+                eprintln!("Warning: Failed to remove checkpoint {:?}: {}", path, e);
+            }
+        }
+    }
+}
+
+/// Indique si l'enregistrement dépasse le seuil de découpage.
+///
+/// # Errors
+/// - `TranscriptionFailed` si l'en-tête WAV est illisible
+pub fn needs_chunking(audio_path: &Path, config: &ChunkingConfig) -> Result<bool, AppError> {
+    Ok(exceeds_threshold(wav_sample_count(audio_path)?, config))
+}
+
+/// Indique si `total` échantillons dépassent le seuil de découpage.
+pub fn exceeds_threshold(total: usize, config: &ChunkingConfig) -> bool {
+    total > config.threshold_secs as usize * SAMPLES_PER_SEC
+}
+
+/// Enregistrements du dossier temporaire ayant un checkpoint, avec leurs paramètres.
+///
+/// Utilisé au démarrage pour reprendre les transcriptions interrompues.
+pub fn find_resumable(temp_dir: &Path) -> Vec<(PathBuf, TranscriptionOptions)> {
+    let Ok(entries) = fs::read_dir(temp_dir) else {
+        return Vec::new();
+    };
+
+    let mut resumable: Vec<(PathBuf, TranscriptionOptions)> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "wav"))
+        .filter_map(|path| {
+            let checkpoint = Checkpoint::load(&path)?;
+            Some((path, checkpoint.options))
+        })
+        .collect();
+
+    // Noms horodatés: l'ordre alphabétique reprend l'ordre d'enregistrement
+    resumable.sort_by(|a, b| a.0.cmp(&b.0));
+    resumable
+}
+
+/// Découpe l'audio en fenêtres coupées au point le plus silencieux près de chaque limite.
+///
+/// # Arguments
+/// * `total` - Nombre total d'échantillons
+/// * `config` - Tailles de fenêtre, recouvrement et rayon de recherche
+/// * `read` - Lit (début, longueur) échantillons; seules les zones de recherche sont lues
+///
+/// # Errors
+/// Erreurs de lecture retournées par `read`.
+pub fn plan_chunks<F>(
+    total: usize,
+    config: &ChunkingConfig,
+    mut read: F,
+) -> Result<Vec<ChunkSpan>, AppError>
+where
+    F: FnMut(usize, usize) -> Result<Vec<f32>, AppError>,
+{
+    let window = config.window_secs as usize * SAMPLES_PER_SEC;
+    let overlap = config.overlap_secs as usize * SAMPLES_PER_SEC;
+    let search = config.silence_search_secs as usize * SAMPLES_PER_SEC;
+
+    let mut spans = Vec::new();
+    let mut keep_from: usize = 0;
+
+    loop {
+        let start = keep_from.saturating_sub(overlap);
+
+        // Dernière fenêtre: le reste tient dans une fenêtre (plus la marge de recherche)
+        if total - start <= window + search {
+            spans.push(ChunkSpan {
+                index: spans.len(),
+                start,
+                end: total,
+                keep_from,
+            });
+            return Ok(spans);
+        }
+
+        let region_start = start + window - search;
+        let region = read(region_start, 2 * search)?;
+        let split = region_start + quietest_point(&region);
+
+        spans.push(ChunkSpan {
+            index: spans.len(),
+            start,
+            end: split,
+            keep_from,
+        });
+        keep_from = split;
+    }
+}
+
+/// Position (milieu de trame) de la trame de 100 ms la moins énergique.
+fn quietest_point(samples: &[f32]) -> usize {
+    if samples.len() < SILENCE_FRAME {
+        return samples.len() / 2;
+    }
+
+    let energy = |frame: &[f32]| frame.iter().map(|s| s * s).sum::<f32>();
+    let (index, _) = samples
+        .chunks_exact(SILENCE_FRAME)
+        .map(energy)
+        .enumerate()
+        .fold(
+            (0, f32::MAX),
+            |best, (i, e)| if e < best.1 { (i, e) } else { best },
+        );
+
+    index * SILENCE_FRAME + SILENCE_FRAME / 2
+}
+
+/// Transcrit un long enregistrement fenêtre par fenêtre, en reprenant son checkpoint s'il existe.
+///
+/// Le checkpoint n'est pas supprimé ici: l'appelant le retire une fois la
+/// transcription terminée ou annulée; il ne reste qu'après un crash ou un arrêt.
+///
+/// # Arguments
+/// * `model` - WhisperModel chargé
+/// * `audio_path` - WAV 16kHz mono
+/// * `options` - Paramètres (ceux du checkpoint priment en cas de reprise)
+/// * `cancel` - Drapeau d'annulation, vérifié entre les fenêtres et pendant le décodage
+/// * `resumable` - Lire et écrire le checkpoint (false pour un WAV en mémoire)
+/// * `on_chunk` - Appelé après chaque fenêtre transcrite
+///
+/// # Errors
+/// - `InvalidAudioFormat` si le WAV est invalide
+/// - `TranscriptionFailed` si une fenêtre échoue ou si la tâche est annulée
+/// - `IoError` si le checkpoint ne peut être écrit
+pub fn transcribe_chunked<F>(
+    model: &WhisperModel,
+    audio_path: &Path,
+    options: &TranscriptionOptions,
+    cancel: Option<&CancelFlag>,
+    resumable: bool,
+    mut on_chunk: F,
+) -> Result<Transcript, AppError>
+where
+    F: FnMut(&ChunkResult),
+{
+    validate_wav_file(audio_path)?;
+    let total = wav_sample_count(audio_path)?;
+
+    let saved = if resumable {
+        Checkpoint::load(audio_path)
+    } else {
+        None
+    };
+    let mut checkpoint = match saved {
+        Some(checkpoint) if checkpoint.total_samples == total => {
+            println!(
+                "Resuming chunked transcription at window {}/{}",
+                checkpoint.completed.len() + 1,
+                checkpoint.spans.len()
+            );
+            checkpoint
+        }
+        _ => Checkpoint {
+            total_samples: total,
+            options: options.clone(),
+            spans: plan_chunks(total, &options.chunking, |start, len| {
+                read_wav_range(audio_path, start, len)
+            })?,
+            detected_language: None,
+            completed: Vec::new(),
+            removed: Vec::new(),
+        },
+    };
+
+    let chunk_total = checkpoint.spans.len();
+    for span in checkpoint
+        .spans
+        .clone()
+        .into_iter()
+        .skip(checkpoint.completed.len())
+    {
+        if cancel.is_some_and(|c| c.load(std::sync::atomic::Ordering::Relaxed)) {
+            return Err(AppError::TranscriptionFailed(
+                TRANSCRIPTION_CANCELLED.to_string(),
+            ));
+        }
+
+        // Langue de la première fenêtre imposée aux suivantes, pour un texte homogène
+        let mut chunk_options = checkpoint.options.clone();
+        if let Some(ref detected) = checkpoint.detected_language {
+            chunk_options.language = Some(detected.code.clone());
+        }
+
+        let samples = read_wav_range(audio_path, span.start, span.end - span.start)?;
+        let transcript = transcribe_samples(model, &samples, &chunk_options, cancel)?;
+        drop(samples);
+
+        if checkpoint.detected_language.is_none() {
+            checkpoint.detected_language = transcript.detected_language.clone();
+        }
+
+        let offset_ms = samples_to_ms(span.start);
+        let keep_from_ms = samples_to_ms(span.keep_from);
+        let mut segments: Vec<Segment> = transcript
+            .segments
+            .into_iter()
+            .map(|segment| shift_segment(segment, offset_ms))
+            // Segments du recouvrement déjà livrés par la fenêtre précédente
+            .filter(|segment| (segment.start_ms + segment.end_ms) / 2 >= keep_from_ms)
+            .collect();
+        let previous = checkpoint.completed.iter().flatten().next_back();
+        continue_speakers(&mut segments, previous);
+        checkpoint.removed.extend(
+            transcript
+                .removed
+                .into_iter()
+                .map(|mut removed| {
+                    removed.start_ms += offset_ms;
+                    removed.end_ms += offset_ms;
+                    removed
+                })
+                .filter(|removed| (removed.start_ms + removed.end_ms) / 2 >= keep_from_ms),
+        );
+
+        let chunk = Transcript::from_segments(segments, 0);
+        checkpoint.completed.push(chunk.segments.clone());
+        if resumable {
+            checkpoint.save(audio_path)?;
+        }
+
+        on_chunk(&ChunkResult {
+            index: span.index,
+            total: chunk_total,
+            start_ms: keep_from_ms,
+            end_ms: samples_to_ms(span.end),
+            text: chunk.text,
+            segments: chunk.segments,
+        });
+    }
+
+    let segments: Vec<Segment> = checkpoint.completed.into_iter().flatten().collect();
+    let mut transcript = Transcript::from_segments(segments, samples_to_ms(total));
+    transcript.translated = checkpoint.options.translate;
+    transcript.source_language = checkpoint.options.language.clone().or(checkpoint
+        .detected_language
+        .as_ref()
+        .map(|d| d.code.clone()));
+    transcript.detected_language = checkpoint.detected_language;
+    transcript.removed = checkpoint.removed;
+
+    println!(
+        "Chunked transcription complete: {} windows, {} segments",
+        chunk_total,
+        transcript.segments.len()
+    );
+
+    Ok(transcript)
+}
+
+/// Décale un segment (et ses mots) de la position de sa fenêtre.
+fn shift_segment(mut segment: Segment, offset_ms: i64) -> Segment {
+    segment.start_ms += offset_ms;
+    segment.end_ms += offset_ms;
+    for word in &mut segment.words {
+        word.start_ms += offset_ms;
+        word.end_ms += offset_ms;
+    }
+    segment
+}
+
+fn samples_to_ms(samples: usize) -> i64 {
+    (samples * 1000 / SAMPLES_PER_SEC) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AppConfig;
+    use crate::transcription::options::{TranscriptionRequest, UserFiles};
+    use crate::transcription::transcript::Word;
+
+    fn config(window: u64, overlap: u64, search: u64) -> ChunkingConfig {
+        ChunkingConfig {
+            threshold_secs: 0,
+            window_secs: window,
+            overlap_secs: overlap,
+            silence_search_secs: search,
+        }
+    }
+
+    /// Signal constant avec un silence d'une seconde commençant à `silence_at` secondes
+    fn signal_with_silence(total_secs: usize, silence_at: &[usize]) -> Vec<f32> {
+        let mut samples = vec![0.5; total_secs * SAMPLES_PER_SEC];
+        for &at in silence_at {
+            let start = at * SAMPLES_PER_SEC;
+            samples[start..start + SAMPLES_PER_SEC].fill(0.0);
+        }
+        samples
+    }
+
+    fn plan(samples: &[f32], config: &ChunkingConfig) -> Vec<ChunkSpan> {
+        plan_chunks(samples.len(), config, |start, len| {
+            Ok(samples[start..(start + len).min(samples.len())].to_vec())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_quietest_point_finds_silence() {
+        let samples = signal_with_silence(3, &[1]);
+        let point = quietest_point(&samples);
+        assert!((SAMPLES_PER_SEC..2 * SAMPLES_PER_SEC).contains(&point));
+    }
+
+    #[test]
+    fn test_short_audio_is_single_chunk() {
+        let samples = signal_with_silence(50, &[]);
+        let spans = plan(&samples, &config(60, 2, 10));
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].start, 0);
+        assert_eq!(spans[0].end, samples.len());
+    }
+
+    #[test]
+    fn test_chunks_split_at_silence_with_overlap() {
+        // Fenêtres de 60s: silences à 55s et 112s, dans les zones de recherche (±10s)
+        let samples = signal_with_silence(150, &[55, 112]);
+        let spans = plan(&samples, &config(60, 2, 10));
+
+        assert_eq!(spans.len(), 3);
+        let first_split = spans[0].end;
+        assert!((55 * SAMPLES_PER_SEC..56 * SAMPLES_PER_SEC).contains(&first_split));
+
+        // La fenêtre suivante reprend 2s avant la coupure, mais ne garde que la suite
+        assert_eq!(spans[1].start, first_split - 2 * SAMPLES_PER_SEC);
+        assert_eq!(spans[1].keep_from, first_split);
+        assert!((112 * SAMPLES_PER_SEC..113 * SAMPLES_PER_SEC).contains(&spans[1].end));
+        assert_eq!(spans[2].end, samples.len());
+    }
+
+    #[test]
+    fn test_chunks_cover_whole_audio() {
+        let samples = signal_with_silence(600, &[]);
+        let spans = plan(&samples, &config(60, 2, 10));
+
+        assert_eq!(spans[0].keep_from, 0);
+        for pair in spans.windows(2) {
+            assert_eq!(pair[1].keep_from, pair[0].end, "No gap between windows");
+            assert!(pair[1].end > pair[0].end);
+        }
+        assert_eq!(spans.last().unwrap().end, samples.len());
+    }
+
+    #[test]
+    fn test_shift_segment_offsets_words() {
+        let segment = Segment {
+            start_ms: 100,
+            end_ms: 900,
+            text: "Bonjour".to_string(),
+            no_speech_probability: 0.0,
+            words: vec![Word {
+                text: "Bonjour".to_string(),
+                start_ms: 100,
+                end_ms: 900,
+                probability: 0.9,
+            }],
+            alternatives: Vec::new(),
+            speaker: None,
+            speaker_turn: false,
+        };
+
+        let shifted = shift_segment(segment, 60_000);
+        assert_eq!(shifted.start_ms, 60_100);
+        assert_eq!(shifted.words[0].end_ms, 60_900);
+    }
+
+    #[test]
+    fn test_checkpoint_roundtrip_and_resumable() {
+        let dir = std::env::temp_dir()
+            .join("vocal-note-taker-tests")
+            .join(format!("chunking_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let audio = dir.join("recording-1-0.wav");
+        fs::write(&audio, b"").unwrap();
+        fs::write(dir.join("recording-2-0.wav"), b"").unwrap();
+
+        let options = TranscriptionOptions::resolve(
+            &AppConfig::default(),
+            &UserFiles::default(),
+            &TranscriptionRequest::default(),
+        )
+        .unwrap();
+        let checkpoint = Checkpoint {
+            total_samples: 42,
+            options: options.clone(),
+            spans: Vec::new(),
+            detected_language: None,
+            completed: vec![Vec::new()],
+            removed: Vec::new(),
+        };
+        checkpoint.save(&audio).unwrap();
+
+        let loaded = Checkpoint::load(&audio);
+        let resumable = find_resumable(&dir);
+        remove_checkpoint(&audio);
+        let after_remove = Checkpoint::load(&audio);
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(loaded, Some(checkpoint));
+        assert_eq!(resumable, vec![(audio, options)]);
+        assert!(after_remove.is_none());
+    }
+}
@@ -0,0 +1,155 @@
+//! Engine module - transcription backend abstraction
+//!
+//! The job worker only talks to a `TranscriptionEngine`: the whisper-rs
+//! implementation in production, a scripted mock in tests. This keeps the
+//! error, cancellation and cleanup paths testable without a 3 GB model.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::audio::memory;
+use crate::audio::DEFAULT_SAMPLE_RATE;
+use crate::error::AppError;
+use crate::transcription::chunking::{self, ChunkResult};
+use crate::transcription::queue::Job;
+use crate::transcription::transcript::Transcript;
+use crate::transcription::whisper::{
+    load_model_if_needed, resolve_model_path, transcribe_audio, transcribe_samples, WhisperModel,
+};
+
+/// Étape signalée par un moteur pendant une transcription.
+#[derive(Debug)]
+pub enum EngineEvent<'a> {
+    /// Le modèle n'est pas en mémoire et va être chargé
+    ModelLoading,
+    /// Modèle prêt, le décodage commence
+    Started,
+    /// Fenêtre terminée d'un long enregistrement
+    Chunk(&'a ChunkResult),
+}
+
+/// Moteur de transcription utilisé par le worker de la file.
+///
+/// Les appels sont bloquants (CPU-intensive): le worker les exécute hors du
+/// runtime async.
+pub trait TranscriptionEngine: Send + Sync {
+    /// Transcrit l'enregistrement d'une tâche (fichier ou échantillons en mémoire).
+    ///
+    /// # Errors
+    /// - Erreurs de chargement du modèle ou de transcription
+    /// - `TranscriptionFailed` (`TRANSCRIPTION_CANCELLED`) si la tâche est annulée
+    fn transcribe(
+        &self,
+        job: &Job,
+        on_event: &mut dyn FnMut(EngineEvent<'_>),
+    ) -> Result<Transcript, AppError>;
+}
+
+/// Moteur whisper-rs: modèle partagé avec `WhisperState`, chargé à la demande.
+pub struct WhisperEngine {
+    model: Arc<Mutex<Option<WhisperModel>>>,
+}
+
+impl WhisperEngine {
+    /// Crée le moteur sur le modèle de `WhisperState::model`.
+    pub fn new(model: Arc<Mutex<Option<WhisperModel>>>) -> Self {
+        Self { model }
+    }
+}
+
+impl TranscriptionEngine for WhisperEngine {
+    fn transcribe(
+        &self,
+        job: &Job,
+        on_event: &mut dyn FnMut(EngineEvent<'_>),
+    ) -> Result<Transcript, AppError> {
+        let path = resolve_model_path(job.options.model.as_deref())?;
+
+        // Si un préchargement est en cours, le verrou fait attendre sa fin
+        let mut guard = self.model.blocking_lock();
+        if guard.as_ref().is_none_or(|model| model.path() != path) {
+            on_event(EngineEvent::ModelLoading);
+        }
+        // Un autre modèle (re-transcription) remplace celui en mémoire
+        if load_model_if_needed(&mut guard, &path)? {
+            println!("Model loaded successfully");
+        }
+        let model = guard.as_mut().expect("model loaded above");
+
+        on_event(EngineEvent::Started);
+
+        let result = transcribe_job(model, job, on_event);
+        // Le délai d'inactivité court à partir de la fin de la transcription
+        model.touch();
+
+        result
+    }
+}
+
+/// Transcrit l'enregistrement d'une tâche, sur disque ou en mémoire.
+fn transcribe_job(
+    model: &WhisperModel,
+    job: &Job,
+    on_event: &mut dyn FnMut(EngineEvent<'_>),
+) -> Result<Transcript, AppError> {
+    match job.samples {
+        Some(ref samples) => transcribe_in_memory(model, samples, job, on_event),
+        None => transcribe_file(model, &job.audio_path, job, on_event),
+    }
+}
+
+/// Transcrit un WAV temporaire, découpé s'il est long ou déjà commencé.
+fn transcribe_file(
+    model: &WhisperModel,
+    audio_path: &Path,
+    job: &Job,
+    on_event: &mut dyn FnMut(EngineEvent<'_>),
+) -> Result<Transcript, AppError> {
+    // Long enregistrement, ou reprise d'une transcription découpée
+    let chunked = chunking::checkpoint_path(audio_path).exists()
+        || chunking::needs_chunking(audio_path, &job.options.chunking).unwrap_or(false);
+
+    if chunked {
+        chunking::transcribe_chunked(
+            model,
+            audio_path,
+            &job.options,
+            Some(&job.cancel),
+            true,
+            |chunk| on_event(EngineEvent::Chunk(chunk)),
+        )
+    } else {
+        transcribe_audio(model, audio_path, &job.options, Some(&job.cancel))
+    }
+}
+
+/// Transcrit un enregistrement gardé en mémoire ([privacy] in_memory).
+///
+/// Les longs enregistrements passent par un memfd pour le découpage en
+/// fenêtres, sans checkpoint; sans memfd, ils sont transcrits d'un bloc.
+fn transcribe_in_memory(
+    model: &WhisperModel,
+    samples: &[f32],
+    job: &Job,
+    on_event: &mut dyn FnMut(EngineEvent<'_>),
+) -> Result<Transcript, AppError> {
+    if chunking::exceeds_threshold(samples.len(), &job.options.chunking) {
+        match memory::memfd_wav(samples, DEFAULT_SAMPLE_RATE) {
+            Ok(wav) => {
+                return chunking::transcribe_chunked(
+                    model,
+                    wav.path(),
+                    &job.options,
+                    Some(&job.cancel),
+                    false,
+                    |chunk| on_event(EngineEvent::Chunk(chunk)),
+                );
+            }
+            Err(e) => eprintln!("Warning: {}; transcribing in a single pass", e),
+        }
+    }
+
+    transcribe_samples(model, samples, &job.options, Some(&job.cancel))
+}
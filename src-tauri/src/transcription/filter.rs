@@ -0,0 +1,225 @@
+//! Filter module - post-decode hallucination removal
+//!
+//! On silent or noisy stretches Whisper invents text: subtitle credits seen in
+//! its training data, or the same line looped over and over. Segments are
+//! dropped when their no-speech probability is high, when they repeat the
+//! previous segment, or when they contain a known phantom phrase.
+//! Every removal is reported with its reason, so nothing disappears silently.
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::loader::FilterConfig;
+use crate::transcription::transcript::Segment;
+
+/// Raison du retrait d'un segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RemovalReason {
+    /// Probabilité d'absence de parole au-delà du seuil
+    NoSpeech,
+    /// Répétition du segment précédent (boucle de décodage)
+    Repetition,
+    /// Phrase fantôme connue (génériques de sous-titres...)
+    PhantomPhrase,
+}
+
+/// Segment retiré par le filtre, rapporté avec le transcript.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemovedSegment {
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub text: String,
+    pub reason: RemovalReason,
+}
+
+impl RemovedSegment {
+    fn new(segment: &Segment, reason: RemovalReason) -> Self {
+        Self {
+            start_ms: segment.start_ms,
+            end_ms: segment.end_ms,
+            text: segment.text.clone(),
+            reason,
+        }
+    }
+}
+
+/// Retire les segments hallucinés.
+///
+/// Ordre des vérifications: phrase fantôme, absence de parole, puis répétition
+/// du dernier segment conservé. Les segments vides sont conservés tels quels.
+///
+/// # Returns
+/// Les segments conservés et ceux retirés, dans l'ordre chronologique.
+pub fn filter_segments(
+    segments: Vec<Segment>,
+    config: &FilterConfig,
+) -> (Vec<Segment>, Vec<RemovedSegment>) {
+    if !config.enabled {
+        return (segments, Vec::new());
+    }
+
+    let phantoms: Vec<String> = config
+        .phantom_phrases
+        .iter()
+        .map(|p| normalize(p))
+        .filter(|p| !p.is_empty())
+        .collect();
+
+    let mut kept: Vec<Segment> = Vec::with_capacity(segments.len());
+    let mut removed = Vec::new();
+    let mut last_text: Option<String> = None;
+
+    for segment in segments {
+        let text = normalize(&segment.text);
+        if text.is_empty() {
+            kept.push(segment);
+            continue;
+        }
+
+        let reason = if phantoms.iter().any(|p| contains_phrase(&text, p)) {
+            Some(RemovalReason::PhantomPhrase)
+        } else if segment.no_speech_probability > config.no_speech_threshold {
+            Some(RemovalReason::NoSpeech)
+        } else if config.collapse_repeats && last_text.as_ref() == Some(&text) {
+            Some(RemovalReason::Repetition)
+        } else {
+            None
+        };
+
+        match reason {
+            Some(reason) => removed.push(RemovedSegment::new(&segment, reason)),
+            None => {
+                last_text = Some(text);
+                kept.push(segment);
+            }
+        }
+    }
+
+    if !removed.is_empty() {
+        println!("Hallucination filter: {} segment(s) removed", removed.len());
+    }
+
+    (kept, removed)
+}
+
+/// Forme de comparaison: minuscules, lettres et chiffres séparés par un espace.
+fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Indique si `text` contient `phrase` en mots entiers (formes normalisées).
+fn contains_phrase(text: &str, phrase: &str) -> bool {
+    format!(" {} ", text).contains(&format!(" {} ", phrase))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(start_ms: i64, text: &str, no_speech_probability: f32) -> Segment {
+        Segment {
+            start_ms,
+            end_ms: start_ms + 1000,
+            text: text.to_string(),
+            no_speech_probability,
+            words: Vec::new(),
+            alternatives: Vec::new(),
+            speaker: None,
+            speaker_turn: false,
+        }
+    }
+
+    #[test]
+    fn test_removes_phantom_phrases() {
+        let segments = vec![
+            segment(0, "On se retrouve demain.", 0.1),
+            segment(
+                1000,
+                "Sous-titres réalisés par la communauté d'Amara.org",
+                0.3,
+            ),
+        ];
+
+        let (kept, removed) = filter_segments(segments, &FilterConfig::default());
+
+        assert_eq!(kept.len(), 1);
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].reason, RemovalReason::PhantomPhrase);
+        assert_eq!(removed[0].start_ms, 1000);
+    }
+
+    #[test]
+    fn test_phantom_phrase_matches_whole_words_only() {
+        let config = FilterConfig {
+            phantom_phrases: vec!["Merci".to_string()],
+            ..FilterConfig::default()
+        };
+        let segments = vec![segment(0, "Mercier arrive demain.", 0.1)];
+
+        let (kept, removed) = filter_segments(segments, &config);
+        assert_eq!(kept.len(), 1);
+        assert!(removed.is_empty());
+    }
+
+    #[test]
+    fn test_removes_high_no_speech_segments() {
+        let segments = vec![segment(0, "Bonjour.", 0.2), segment(1000, "Hmm.", 0.95)];
+
+        let (kept, removed) = filter_segments(segments, &FilterConfig::default());
+
+        assert_eq!(kept[0].text, "Bonjour.");
+        assert_eq!(removed[0].reason, RemovalReason::NoSpeech);
+    }
+
+    #[test]
+    fn test_collapses_repeated_segments() {
+        let segments = vec![
+            segment(0, "Le budget est validé.", 0.1),
+            segment(1000, "le budget est validé", 0.1),
+            segment(2000, "Le budget est validé.", 0.1),
+            segment(3000, "Point suivant.", 0.1),
+            segment(4000, "Le budget est validé.", 0.1),
+        ];
+
+        let (kept, removed) = filter_segments(segments, &FilterConfig::default());
+
+        let texts: Vec<_> = kept.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec![
+                "Le budget est validé.",
+                "Point suivant.",
+                "Le budget est validé."
+            ]
+        );
+        assert_eq!(removed.len(), 2);
+        assert!(removed
+            .iter()
+            .all(|r| r.reason == RemovalReason::Repetition));
+    }
+
+    #[test]
+    fn test_disabled_filter_keeps_everything() {
+        let config = FilterConfig {
+            enabled: false,
+            ..FilterConfig::default()
+        };
+        let segments = vec![segment(0, "Thanks for watching!", 0.99)];
+
+        let (kept, removed) = filter_segments(segments, &config);
+        assert_eq!(kept.len(), 1);
+        assert!(removed.is_empty());
+    }
+
+    #[test]
+    fn test_removed_segment_serialization() {
+        let removed = RemovedSegment::new(&segment(0, "Hmm.", 0.9), RemovalReason::NoSpeech);
+        let json = serde_json::to_value(removed).unwrap();
+        assert_eq!(json["reason"], "no_speech");
+        assert_eq!(json["text"], "Hmm.");
+    }
+}
@@ -0,0 +1,364 @@
+//! Glossary module - custom vocabulary for Whisper's initial prompt
+//!
+//! Whisper conditions its decoding on the initial prompt: listing product
+//! names, colleagues' names and jargon there makes it spell them correctly.
+//! The prompt is trimmed to Whisper's token budget.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::config::loader::GlossaryConfig;
+use crate::error::AppError;
+
+/// Budget de tokens du prompt initial (whisper.cpp garde n_text_ctx / 2 = 224 tokens)
+pub const MAX_PROMPT_TOKENS: usize = 224;
+
+/// Nom du fichier glossaire par défaut, dans le dossier de configuration
+const DEFAULT_GLOSSARY_FILE: &str = "glossary.txt";
+
+/// Glossaire chargé: termes globaux et groupes nommés.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Glossary {
+    /// Termes toujours inclus
+    pub terms: Vec<String>,
+    /// Groupes de termes sélectionnables par profil
+    pub groups: HashMap<String, Vec<String>>,
+}
+
+impl Glossary {
+    /// Termes et groupes de la section [glossary] seule, sans fichier.
+    pub fn from_config(config: &GlossaryConfig) -> Self {
+        Self {
+            terms: config.terms.clone(),
+            groups: config.groups.clone(),
+        }
+    }
+
+    /// Charge le glossaire depuis la section [glossary] et le fichier glossaire.
+    ///
+    /// Sans `file` configuré, glossary.txt est cherché dans `config_dir`.
+    ///
+    /// # Errors
+    /// - `ConfigurationError` si le fichier configuré est illisible
+    pub fn load(config: &GlossaryConfig, config_dir: Option<&Path>) -> Result<Self, AppError> {
+        let mut glossary = Self::from_config(config);
+
+        let file = match config.file {
+            Some(ref file) => Some(file.clone()),
+            None => config_dir
+                .map(|dir| dir.join(DEFAULT_GLOSSARY_FILE))
+                .filter(|path| path.exists()),
+        };
+
+        if let Some(path) = file {
+            let content = fs::read_to_string(&path).map_err(|e| {
+                AppError::ConfigurationError(format!(
+                    "Glossaire illisible {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+            glossary.merge(parse_glossary(&content));
+        }
+
+        Ok(glossary)
+    }
+
+    /// Ajoute les termes et groupes d'un autre glossaire.
+    fn merge(&mut self, other: Glossary) {
+        self.terms.extend(other.terms);
+        for (name, terms) in other.groups {
+            self.groups.entry(name).or_default().extend(terms);
+        }
+    }
+
+    /// Sélectionne les termes à envoyer à Whisper.
+    ///
+    /// Termes globaux + groupes demandés, ou tous les groupes si `groups` est `None`.
+    /// Les doublons (insensibles à la casse) sont retirés, l'ordre est conservé.
+    ///
+    /// # Errors
+    /// - `ConfigurationError` si un groupe demandé n'existe pas
+    pub fn select(&self, groups: Option<&[String]>) -> Result<Vec<String>, AppError> {
+        let mut selected: Vec<&String> = self.terms.iter().collect();
+
+        match groups {
+            Some(names) => {
+                for name in names {
+                    let terms = self.groups.get(name).ok_or_else(|| {
+                        AppError::ConfigurationError(format!(
+                            "Groupe de glossaire inconnu: '{}'",
+                            name
+                        ))
+                    })?;
+                    selected.extend(terms);
+                }
+            }
+            None => {
+                // Ordre stable entre deux transcriptions malgré le HashMap
+                let mut names: Vec<&String> = self.groups.keys().collect();
+                names.sort();
+                for name in names {
+                    selected.extend(&self.groups[name]);
+                }
+            }
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        Ok(selected
+            .into_iter()
+            .map(|t| t.trim())
+            .filter(|t| !t.is_empty() && seen.insert(t.to_lowercase()))
+            .map(str::to_string)
+            .collect())
+    }
+}
+
+/// Parse un fichier glossaire.
+///
+/// Format: un terme par ligne, `#` pour les commentaires, `[nom]` ouvre un groupe.
+/// Les termes avant le premier groupe sont globaux.
+pub fn parse_glossary(content: &str) -> Glossary {
+    let mut glossary = Glossary::default();
+    let mut current_group: Option<String> = None;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            let name = name.trim().to_string();
+            glossary.groups.entry(name.clone()).or_default();
+            current_group = Some(name);
+            continue;
+        }
+
+        match current_group {
+            Some(ref name) => glossary
+                .groups
+                .entry(name.clone())
+                .or_default()
+                .push(line.to_string()),
+            None => glossary.terms.push(line.to_string()),
+        }
+    }
+
+    glossary
+}
+
+/// Construit le prompt initial: texte libre puis termes du glossaire séparés par des virgules.
+///
+/// Les termes sont ajoutés tant que le prompt reste dans `max_tokens`; les
+/// suivants sont abandonnés. Le texte libre est tronqué mot par mot s'il dépasse
+/// à lui seul le budget.
+///
+/// # Arguments
+/// * `prefix` - Texte libre (contexte, style) placé en tête
+/// * `terms` - Termes du glossaire, par ordre de priorité
+/// * `max_tokens` - Budget de tokens
+/// * `count_tokens` - Compte les tokens d'un texte (tokenizer du modèle)
+///
+/// # Returns
+/// `None` si le prompt est vide
+pub fn build_initial_prompt<F>(
+    prefix: Option<&str>,
+    terms: &[String],
+    max_tokens: usize,
+    count_tokens: F,
+) -> Option<String>
+where
+    F: Fn(&str) -> usize,
+{
+    let mut prompt = String::new();
+
+    if let Some(prefix) = prefix.map(str::trim).filter(|p| !p.is_empty()) {
+        for word in prefix.split_whitespace() {
+            let candidate = join_prompt(&prompt, word, " ");
+            if count_tokens(&candidate) > max_tokens {
+                break;
+            }
+            prompt = candidate;
+        }
+    }
+
+    let mut first_term = true;
+    for term in terms {
+        let separator = if prompt.is_empty() {
+            ""
+        } else if first_term {
+            " "
+        } else {
+            ", "
+        };
+        let candidate = join_prompt(&prompt, term, separator);
+        if count_tokens(&candidate) > max_tokens {
+            println!(
+                "Glossary trimmed to token budget ({} tokens): '{}' and following terms skipped",
+                max_tokens, term
+            );
+            break;
+        }
+        prompt = candidate;
+        first_term = false;
+    }
+
+    if prompt.is_empty() {
+        None
+    } else {
+        Some(prompt)
+    }
+}
+
+/// Concatène `addition` à `prompt` avec le séparateur (aucun si prompt vide).
+fn join_prompt(prompt: &str, addition: &str, separator: &str) -> String {
+    if prompt.is_empty() {
+        addition.to_string()
+    } else {
+        format!("{}{}{}", prompt, separator, addition)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// Approximation pour les tests: un token par mot
+    fn count_words(text: &str) -> usize {
+        text.split_whitespace().count()
+    }
+
+    fn terms(list: &[&str]) -> Vec<String> {
+        list.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_glossary_file() {
+        let glossary = parse_glossary(
+            "# Produits\nVocal Note Taker\nWhisper\n\n[equipe]\nAmélie\n  Joachim  \n[vide]\n",
+        );
+
+        assert_eq!(glossary.terms, terms(&["Vocal Note Taker", "Whisper"]));
+        assert_eq!(glossary.groups["equipe"], terms(&["Amélie", "Joachim"]));
+        assert!(glossary.groups["vide"].is_empty());
+    }
+
+    #[test]
+    fn test_select_all_groups_when_none() {
+        let mut glossary = parse_glossary("Tamles\n[b]\nJoachim\n[a]\nAmélie\n");
+        glossary.terms.push("tamles".to_string());
+
+        let selected = glossary.select(None).unwrap();
+        assert_eq!(selected, terms(&["Tamles", "Amélie", "Joachim"]));
+    }
+
+    #[test]
+    fn test_select_named_groups() {
+        let glossary = parse_glossary("Tamles\n[a]\nAmélie\n[b]\nJoachim\n");
+        let selected = glossary.select(Some(&terms(&["b"]))).unwrap();
+        assert_eq!(selected, terms(&["Tamles", "Joachim"]));
+    }
+
+    #[test]
+    fn test_select_unknown_group_is_error() {
+        let glossary = parse_glossary("[a]\nAmélie\n");
+        let result = glossary.select(Some(&terms(&["inconnu"])));
+        match result {
+            Err(AppError::ConfigurationError(msg)) => assert!(msg.contains("inconnu")),
+            other => panic!("Expected ConfigurationError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_merges_config_and_file() {
+        let path = std::env::temp_dir().join(format!(
+            "vocal-note-taker-glossary-{}.txt",
+            std::process::id()
+        ));
+        fs::write(&path, "Whisper\n[equipe]\nJoachim\n").unwrap();
+
+        let mut config = GlossaryConfig {
+            file: Some(path.clone()),
+            terms: terms(&["Tamles"]),
+            ..Default::default()
+        };
+        config
+            .groups
+            .insert("equipe".to_string(), terms(&["Amélie"]));
+
+        let glossary = Glossary::load(&config, None);
+        let _ = fs::remove_file(&path);
+        let glossary = glossary.unwrap();
+
+        assert_eq!(glossary.terms, terms(&["Tamles", "Whisper"]));
+        assert_eq!(glossary.groups["equipe"], terms(&["Amélie", "Joachim"]));
+    }
+
+    #[test]
+    fn test_load_missing_configured_file_is_error() {
+        let config = GlossaryConfig {
+            file: Some(PathBuf::from("/nonexistent/glossary.txt")),
+            ..Default::default()
+        };
+        assert!(matches!(
+            Glossary::load(&config, None),
+            Err(AppError::ConfigurationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_load_default_file_from_config_dir() {
+        let dir = std::env::temp_dir().join(format!(
+            "vocal-note-taker-glossary-dir-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(DEFAULT_GLOSSARY_FILE), "Whisper\n").unwrap();
+
+        let glossary = Glossary::load(&GlossaryConfig::default(), Some(&dir));
+        let without_dir = Glossary::load(&GlossaryConfig::default(), None);
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(glossary.unwrap().terms, terms(&["Whisper"]));
+        assert!(without_dir.unwrap().terms.is_empty());
+    }
+
+    #[test]
+    fn test_build_prompt_joins_terms() {
+        let prompt = build_initial_prompt(
+            Some("Réunion produit."),
+            &terms(&["Tamles", "Whisper"]),
+            MAX_PROMPT_TOKENS,
+            count_words,
+        );
+        assert_eq!(prompt.as_deref(), Some("Réunion produit. Tamles, Whisper"));
+    }
+
+    #[test]
+    fn test_build_prompt_trims_to_budget() {
+        let prompt = build_initial_prompt(
+            None,
+            &terms(&["un", "deux", "trois", "quatre"]),
+            3,
+            count_words,
+        );
+        assert_eq!(prompt.as_deref(), Some("un, deux, trois"));
+    }
+
+    #[test]
+    fn test_build_prompt_truncates_long_prefix() {
+        let prompt = build_initial_prompt(Some("a b c d e f"), &terms(&["Tamles"]), 4, count_words);
+        assert_eq!(prompt.as_deref(), Some("a b c d"));
+    }
+
+    #[test]
+    fn test_build_prompt_empty() {
+        assert_eq!(
+            build_initial_prompt(Some("  "), &[], MAX_PROMPT_TOKENS, count_words),
+            None
+        );
+    }
+}
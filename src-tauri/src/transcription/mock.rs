@@ -0,0 +1,156 @@
+//! Mock engine - scripted `TranscriptionEngine` for tests
+//!
+//! Returns queued results in order (text, chunked text or error), optionally
+//! after a delay that honours cancellation like whisper.cpp's abort callback.
+//! The first call reports a model load, as the lazy-loaded Whisper model does.
+
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::error::AppError;
+use crate::transcription::chunking::ChunkResult;
+use crate::transcription::engine::{EngineEvent, TranscriptionEngine};
+use crate::transcription::queue::Job;
+use crate::transcription::transcript::{Segment, Transcript};
+use crate::transcription::whisper::TRANSCRIPTION_CANCELLED;
+
+/// Intervalle de vérification de l'annulation pendant un délai simulé
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Réponse scriptée pour un appel à `transcribe`.
+#[derive(Debug, Clone)]
+struct MockStep {
+    delay: Duration,
+    /// Textes des fenêtres (une seule pour un enregistrement court)
+    chunks: Vec<String>,
+    error: Option<AppError>,
+}
+
+/// Moteur de test: rejoue les réponses scriptées, dans l'ordre.
+#[derive(Debug, Default)]
+pub struct MockEngine {
+    steps: Mutex<VecDeque<MockStep>>,
+    calls: Mutex<Vec<PathBuf>>,
+}
+
+impl MockEngine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prochain appel: transcription immédiate de `text`.
+    pub fn then_text(self, text: &str) -> Self {
+        self.then_delay(Duration::ZERO, text)
+    }
+
+    /// Prochain appel: `text` après `delay`, sauf annulation entre-temps.
+    pub fn then_delay(self, delay: Duration, text: &str) -> Self {
+        self.push(MockStep {
+            delay,
+            chunks: vec![text.to_string()],
+            error: None,
+        })
+    }
+
+    /// Prochain appel: long enregistrement transcrit fenêtre par fenêtre.
+    pub fn then_chunks(self, texts: &[&str]) -> Self {
+        self.push(MockStep {
+            delay: Duration::ZERO,
+            chunks: texts.iter().map(|t| t.to_string()).collect(),
+            error: None,
+        })
+    }
+
+    /// Prochain appel: échec avec `error`.
+    pub fn then_error(self, error: AppError) -> Self {
+        self.push(MockStep {
+            delay: Duration::ZERO,
+            chunks: Vec::new(),
+            error: Some(error),
+        })
+    }
+
+    /// Enregistrements transcrits, dans l'ordre des appels.
+    pub fn calls(&self) -> Vec<PathBuf> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    fn push(self, step: MockStep) -> Self {
+        self.steps.lock().unwrap().push_back(step);
+        self
+    }
+}
+
+impl TranscriptionEngine for MockEngine {
+    fn transcribe(
+        &self,
+        job: &Job,
+        on_event: &mut dyn FnMut(EngineEvent<'_>),
+    ) -> Result<Transcript, AppError> {
+        let first_call = {
+            let mut calls = self.calls.lock().unwrap();
+            calls.push(job.audio_path.clone());
+            calls.len() == 1
+        };
+        let step = self
+            .steps
+            .lock()
+            .unwrap()
+            .pop_front()
+            .expect("MockEngine: no scripted result left");
+
+        if first_call {
+            on_event(EngineEvent::ModelLoading);
+        }
+        on_event(EngineEvent::Started);
+
+        let started = Instant::now();
+        while started.elapsed() < step.delay {
+            if job.cancel.load(Ordering::Relaxed) {
+                return Err(AppError::TranscriptionFailed(
+                    TRANSCRIPTION_CANCELLED.to_string(),
+                ));
+            }
+            std::thread::sleep(CANCEL_POLL_INTERVAL);
+        }
+
+        if let Some(error) = step.error {
+            return Err(error);
+        }
+
+        let total = step.chunks.len();
+        let segments: Vec<Segment> = step
+            .chunks
+            .iter()
+            .enumerate()
+            .map(|(index, text)| {
+                let segment = Segment {
+                    start_ms: index as i64 * 1000,
+                    end_ms: (index as i64 + 1) * 1000,
+                    text: text.clone(),
+                    no_speech_probability: 0.0,
+                    words: Vec::new(),
+                    alternatives: Vec::new(),
+                    speaker: None,
+                    speaker_turn: false,
+                };
+                if total > 1 {
+                    on_event(EngineEvent::Chunk(&ChunkResult {
+                        index,
+                        total,
+                        start_ms: segment.start_ms,
+                        end_ms: segment.end_ms,
+                        text: segment.text.clone(),
+                        segments: vec![segment.clone()],
+                    }));
+                }
+                segment
+            })
+            .collect();
+
+        Ok(Transcript::from_segments(segments, total as i64 * 1000))
+    }
+}
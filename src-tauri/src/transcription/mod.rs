@@ -2,13 +2,50 @@
 //!
 //! Submodules:
 //! - whisper: whisper-rs binding for local transcription
+//! - models: local model import and discovery
+//! - glossary: custom vocabulary turned into Whisper's initial prompt
+//! - options: per-request settings resolution (request > profile > config)
+//! - transcript: structured result (timed segments, words + metadata)
+//! - benchmark: local model / thread-count comparison
+//! - queue: ordered transcription jobs with IDs and cancellation
+//! - chunking: long recordings split at silences, with resumable checkpoints
+//! - filter: post-decode removal of hallucinated segments
+//! - retention: last recording kept for re-transcription (opt-in)
+//! - engine: transcription backend trait (whisper-rs implementation)
+//! - worker: runs a queued job end to end (events, cleanup)
+//! - mock: scripted engine for tests
 //!
 //! 100% local processing - no cloud fallback (NFR-SEC-1).
 
+pub mod benchmark;
+pub mod chunking;
+pub mod engine;
+pub mod filter;
+pub mod glossary;
+#[cfg(test)]
+pub mod mock;
+pub mod models;
+pub mod options;
+pub mod queue;
+pub mod retention;
+pub mod transcript;
 pub mod whisper;
+pub mod worker;
 
 // Re-exports for convenient access
+pub use benchmark::BenchmarkResult;
+pub use chunking::ChunkResult;
+pub use engine::{TranscriptionEngine, WhisperEngine};
+pub use filter::{RemovalReason, RemovedSegment};
+pub use models::{find_installed_model, list_installed_models, ModelInfo};
+pub use options::{TranscriptionOptions, TranscriptionRequest, UserFiles};
+pub use queue::{CancelOutcome, JobId, JobInfo, JobQueueState};
+pub use retention::RetentionState;
+pub use transcript::{
+    group_tokens_into_words, DetectedLanguage, Segment, Speaker, TokenTiming, Transcript, Word,
+};
 pub use whisper::{
-    check_model_availability, ensure_model_dir, get_model_path, transcribe_audio, WhisperModel,
+    check_model_availability, ensure_model_dir, get_model_path, load_model_if_needed,
+    resolve_model_path, transcribe_audio, transcribe_samples, validate_options, WhisperModel,
     WhisperState,
 };
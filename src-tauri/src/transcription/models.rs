@@ -0,0 +1,369 @@
+//! Model management module - local model import and discovery
+//!
+//! Installs Whisper models from a local file (USB stick, shared drive...)
+//! into ~/.local/share/vocal-note-taker/models/.
+//! No network access involved (NFR-SEC-1).
+
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::error::AppError;
+
+/// Magic number en tête des fichiers modèle whisper.cpp ("ggml", little-endian)
+const GGML_MAGIC: u32 = 0x6767_6d6c;
+
+/// Taille minimale plausible d'un modèle (ggml-tiny fait ~75 MB)
+const MIN_MODEL_SIZE: u64 = 1024 * 1024;
+
+/// Taille des blocs de copie (8 MiB)
+const COPY_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Extension des fichiers modèle whisper.cpp
+const MODEL_EXTENSION: &str = "bin";
+
+/// Informations sur un modèle installé.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ModelInfo {
+    /// Nom du fichier (ex: "ggml-large-v3.bin")
+    pub name: String,
+    /// Chemin complet dans le dossier des modèles
+    pub path: PathBuf,
+    /// Taille en octets
+    pub size_bytes: u64,
+}
+
+/// Returns the directory where Whisper models are stored.
+///
+/// Location: ~/.local/share/vocal-note-taker/models/
+///
+/// # Errors
+/// Returns `ConfigurationError` if the system data directory cannot be determined.
+pub fn get_models_dir() -> Result<PathBuf, AppError> {
+    let mut path = dirs::data_local_dir().ok_or_else(|| {
+        AppError::ConfigurationError(
+            "Impossible de déterminer le répertoire de données local".to_string(),
+        )
+    })?;
+    path.push("vocal-note-taker");
+    path.push("models");
+    Ok(path)
+}
+
+/// Indique si le chemin porte l'extension des modèles whisper.cpp.
+fn has_model_extension(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == MODEL_EXTENSION)
+}
+
+/// Vérifie qu'un fichier ressemble à un modèle whisper.cpp valide.
+///
+/// Contrôle l'extension, la taille minimale et le magic number GGML en tête de fichier.
+///
+/// # Errors
+/// - `ModelImportFailed` si le fichier est illisible ou n'est pas un modèle GGML
+pub fn validate_model_file(path: &Path) -> Result<u64, AppError> {
+    if !has_model_extension(path) {
+        return Err(AppError::ModelImportFailed(format!(
+            "Extension .{} attendue: {}",
+            MODEL_EXTENSION,
+            path.display()
+        )));
+    }
+
+    let metadata = fs::metadata(path).map_err(|e| {
+        AppError::ModelImportFailed(format!("Fichier illisible '{}': {}", path.display(), e))
+    })?;
+
+    if !metadata.is_file() {
+        return Err(AppError::ModelImportFailed(format!(
+            "Pas un fichier: {}",
+            path.display()
+        )));
+    }
+
+    let size = metadata.len();
+    if size < MIN_MODEL_SIZE {
+        return Err(AppError::ModelImportFailed(format!(
+            "Fichier trop petit pour un modèle Whisper ({} octets)",
+            size
+        )));
+    }
+
+    let mut magic = [0u8; 4];
+    File::open(path)
+        .and_then(|mut f| f.read_exact(&mut magic))
+        .map_err(|e| {
+            AppError::ModelImportFailed(format!("Lecture de l'en-tête impossible: {}", e))
+        })?;
+
+    if u32::from_le_bytes(magic) != GGML_MAGIC {
+        return Err(AppError::ModelImportFailed(
+            "En-tête GGML absent - le fichier n'est pas un modèle whisper.cpp".to_string(),
+        ));
+    }
+
+    Ok(size)
+}
+
+/// Liste les modèles installés dans le dossier des modèles, triés par nom.
+///
+/// Retourne une liste vide si le dossier n'existe pas encore.
+pub fn list_installed_models() -> Result<Vec<ModelInfo>, AppError> {
+    let models_dir = get_models_dir()?;
+    if !models_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut models: Vec<ModelInfo> = fs::read_dir(&models_dir)?
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            if !has_model_extension(&path) {
+                return None;
+            }
+            let metadata = entry.metadata().ok()?;
+            if !metadata.is_file() {
+                return None;
+            }
+            Some(ModelInfo {
+                name: path.file_name()?.to_string_lossy().to_string(),
+                path,
+                size_bytes: metadata.len(),
+            })
+        })
+        .collect();
+
+    models.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(models)
+}
+
+/// Chemin d'un modèle installé, désigné par son nom de fichier.
+///
+/// # Errors
+/// - `ModelNotFound` si le nom n'est pas celui d'un modèle du dossier des modèles
+pub fn find_installed_model(name: &str) -> Result<PathBuf, AppError> {
+    find_model_in(&get_models_dir()?, name)
+}
+
+/// Cherche `name` dans `models_dir`, sans jamais en sortir.
+fn find_model_in(models_dir: &Path, name: &str) -> Result<PathBuf, AppError> {
+    let path = models_dir.join(name);
+    // Un simple nom de fichier: pas de séparateur ni de composant ".."
+    let is_plain_name = Path::new(name).file_name().is_some_and(|n| n == name);
+
+    if !is_plain_name || !has_model_extension(&path) || !path.is_file() {
+        return Err(AppError::ModelNotFound(format!(
+            "Modèle non installé: {}",
+            name
+        )));
+    }
+    Ok(path)
+}
+
+/// Importe un modèle depuis un fichier local vers le dossier des modèles.
+///
+/// La copie passe par un fichier `.part` renommé une fois vérifié, pour qu'un
+/// import interrompu ne laisse jamais un modèle tronqué visible.
+///
+/// # Arguments
+/// * `source` - Chemin du fichier modèle à importer
+/// * `on_progress` - Appelé après chaque bloc copié avec (octets copiés, taille totale)
+///
+/// # Errors
+/// - `ModelImportFailed` si la source est invalide, si un modèle du même nom existe déjà,
+///   ou si la copie/vérification échoue
+pub fn import_model<F>(source: &Path, mut on_progress: F) -> Result<ModelInfo, AppError>
+where
+    F: FnMut(u64, u64),
+{
+    let total = validate_model_file(source)?;
+
+    let name = source
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .ok_or_else(|| AppError::ModelImportFailed("Nom de fichier invalide".to_string()))?;
+
+    let models_dir = get_models_dir()?;
+    fs::create_dir_all(&models_dir)?;
+
+    let destination = models_dir.join(&name);
+    if destination.exists() {
+        return Err(AppError::ModelImportFailed(format!(
+            "Un modèle nommé '{}' est déjà installé",
+            name
+        )));
+    }
+
+    let part_path = models_dir.join(format!("{}.part", name));
+    let result = copy_with_progress(source, &part_path, total, &mut on_progress)
+        .and_then(|copied| {
+            if copied != total {
+                return Err(AppError::ModelImportFailed(format!(
+                    "Copie incomplète: {} / {} octets",
+                    copied, total
+                )));
+            }
+            // Revérifier la copie avant de l'exposer sous son nom définitif
+            fs::rename(&part_path, &destination)?;
+            validate_model_file(&destination)
+        });
+
+    match result {
+        Ok(size_bytes) => {
+            println!("Model imported: {} ({} bytes)", destination.display(), size_bytes);
+            Ok(ModelInfo {
+                name,
+                path: destination,
+                size_bytes,
+            })
+        }
+        Err(e) => {
+            let _ = fs::remove_file(&part_path);
+            let _ = fs::remove_file(&destination);
+            Err(e)
+        }
+    }
+}
+
+/// Copie `source` vers `destination` par blocs en signalant la progression.
+fn copy_with_progress<F>(
+    source: &Path,
+    destination: &Path,
+    total: u64,
+    on_progress: &mut F,
+) -> Result<u64, AppError>
+where
+    F: FnMut(u64, u64),
+{
+    let mut reader = File::open(source).map_err(|e| {
+        AppError::ModelImportFailed(format!("Ouverture de la source impossible: {}", e))
+    })?;
+    let mut writer = File::create(destination).map_err(|e| {
+        AppError::ModelImportFailed(format!("Création du fichier cible impossible: {}", e))
+    })?;
+
+    let mut buffer = vec![0u8; COPY_CHUNK_SIZE];
+    let mut copied: u64 = 0;
+
+    loop {
+        let read = reader
+            .read(&mut buffer)
+            .map_err(|e| AppError::ModelImportFailed(format!("Erreur de lecture: {}", e)))?;
+        if read == 0 {
+            break;
+        }
+        writer
+            .write_all(&buffer[..read])
+            .map_err(|e| AppError::ModelImportFailed(format!("Erreur d'écriture: {}", e)))?;
+        copied += read as u64;
+        on_progress(copied, total);
+    }
+
+    writer
+        .sync_all()
+        .map_err(|e| AppError::ModelImportFailed(format!("Erreur d'écriture: {}", e)))?;
+
+    Ok(copied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    /// Crée un faux modèle GGML dans un dossier de test isolé
+    fn create_fake_model(name: &str, magic: u32, size: usize) -> PathBuf {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir()
+            .join("vocal-note-taker-tests")
+            .join(format!("models_{}", timestamp));
+        fs::create_dir_all(&dir).unwrap();
+
+        let path = dir.join(name);
+        let mut data = vec![0u8; size];
+        data[..4].copy_from_slice(&magic.to_le_bytes());
+        fs::write(&path, data).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_find_model_in_stays_in_models_dir() {
+        let model = create_fake_model("ggml-medium.bin", GGML_MAGIC, 1024);
+        let dir = model.parent().unwrap();
+
+        assert_eq!(find_model_in(dir, "ggml-medium.bin").unwrap(), model);
+        for name in ["ggml-small.bin", "../ggml-medium.bin", "ggml-medium", ""] {
+            assert!(
+                matches!(find_model_in(dir, name), Err(AppError::ModelNotFound(_))),
+                "{:?} must not resolve",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_models_dir_returns_valid_path() {
+        let dir = get_models_dir().unwrap();
+        assert!(dir.to_string_lossy().contains("vocal-note-taker"));
+        assert!(dir.ends_with("models"));
+    }
+
+    #[test]
+    fn test_validate_model_file_accepts_ggml() {
+        let path = create_fake_model("ggml-test.bin", GGML_MAGIC, MIN_MODEL_SIZE as usize);
+        let result = validate_model_file(&path);
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+        assert_eq!(result.unwrap(), MIN_MODEL_SIZE);
+    }
+
+    #[test]
+    fn test_validate_model_file_rejects_bad_magic() {
+        let path = create_fake_model("ggml-bad.bin", 0xdead_beef, MIN_MODEL_SIZE as usize);
+        let result = validate_model_file(&path);
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+        match result {
+            Err(AppError::ModelImportFailed(msg)) => assert!(msg.contains("GGML")),
+            other => panic!("Expected ModelImportFailed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_model_file_rejects_small_file() {
+        let path = create_fake_model("ggml-small.bin", GGML_MAGIC, 16);
+        let result = validate_model_file(&path);
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+        assert!(matches!(result, Err(AppError::ModelImportFailed(_))));
+    }
+
+    #[test]
+    fn test_validate_model_file_rejects_wrong_extension() {
+        let result = validate_model_file(Path::new("/tmp/model.gguf"));
+        match result {
+            Err(AppError::ModelImportFailed(msg)) => assert!(msg.contains(".bin")),
+            other => panic!("Expected ModelImportFailed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_copy_with_progress_reports_total() {
+        let source = create_fake_model("ggml-copy.bin", GGML_MAGIC, COPY_CHUNK_SIZE + 10);
+        let destination = source.with_extension("bin.part");
+
+        let mut calls = Vec::new();
+        let copied = copy_with_progress(
+            &source,
+            &destination,
+            (COPY_CHUNK_SIZE + 10) as u64,
+            &mut |done, total| calls.push((done, total)),
+        )
+        .unwrap();
+        let _ = fs::remove_dir_all(source.parent().unwrap());
+
+        assert_eq!(copied, (COPY_CHUNK_SIZE + 10) as u64);
+        assert!(calls.len() >= 2, "Should report progress per chunk");
+        assert_eq!(calls.last().unwrap().0, copied);
+    }
+}
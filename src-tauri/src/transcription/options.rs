@@ -0,0 +1,425 @@
+//! Transcription options - per-request settings resolution
+//!
+//! Effective settings are resolved in priority order:
+//! command arguments > profile > [transcription] section of config.toml
+//! ([postprocess] for text post-processing).
+//!
+//! The user's glossary and rules files are read once at startup
+//! (`UserFiles`), never while resolving a request: a broken file must not
+//! cost a recording.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::loader::{ChunkingConfig, DecodingConfig, FilterConfig, PostProcessConfig};
+use crate::config::AppConfig;
+use crate::error::AppError;
+use crate::text::rules::{self, RuleSet};
+use crate::transcription::glossary::Glossary;
+
+/// Valeur de langue déclenchant l'auto-détection
+const AUTO_LANGUAGE: &str = "auto";
+
+/// Paramètres optionnels fournis par le frontend à `start_transcription`.
+/// Chaque champ absent est repris du profil puis de la configuration.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct TranscriptionRequest {
+    /// Profil à appliquer (sinon `active_profile` de config.toml)
+    pub profile: Option<String>,
+    /// Code langue ISO 639-1 ou "auto"
+    pub language: Option<String>,
+    /// Traduire vers l'anglais au lieu de transcrire
+    pub translate: Option<bool>,
+    /// Marquer les changements de locuteur
+    pub diarize: Option<bool>,
+    /// Texte libre placé en tête du prompt initial
+    pub initial_prompt: Option<String>,
+    /// Modèle installé à utiliser (nom de fichier, ex: "ggml-medium.bin")
+    pub model: Option<String>,
+}
+
+/// Paramètres effectifs passés à `transcribe_audio`.
+///
+/// Sérialisables pour être repris tels quels depuis un checkpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionOptions {
+    /// Langue forcée, ou `None` pour l'auto-détection
+    pub language: Option<String>,
+    /// Tâche "translate" de Whisper: sortie en anglais quelle que soit la langue source
+    pub translate: bool,
+    /// Tours de parole tinydiarize (Speaker A / Speaker B)
+    #[serde(default)]
+    pub diarize: bool,
+    /// Texte libre placé en tête du prompt initial
+    pub initial_prompt: Option<String>,
+    /// Termes du glossaire sélectionnés, par ordre de priorité
+    pub vocabulary: Vec<String>,
+    /// Threads CPU, ou `None` pour le choix automatique
+    pub n_threads: Option<usize>,
+    /// Stratégie de décodage effective (mode déterministe déjà appliqué)
+    pub decoding: DecodingConfig,
+    /// Découpage des enregistrements longs
+    pub chunking: ChunkingConfig,
+    /// Filtrage des hallucinations
+    #[serde(default)]
+    pub filter: FilterConfig,
+    /// Modèle installé à utiliser (requête, puis [model] name), ou `None`
+    /// pour le modèle par défaut
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Corrections du texte (surcharges du profil appliquées)
+    #[serde(default)]
+    pub postprocess: PostProcessConfig,
+    /// Règles de remplacement chargées du fichier de règles, déjà compilées
+    #[serde(default)]
+    pub rules: RuleSet,
+    /// Commandes d'édition reconnues en tête d'enregistrement ([note] voice_commands)
+    #[serde(default)]
+    pub voice_commands: bool,
+}
+
+/// Fichiers de l'utilisateur chargés au démarrage (State managed by Tauri).
+///
+/// Un fichier illisible est signalé puis ignoré: la transcription continue
+/// sans lui.
+#[derive(Debug, Clone, Default)]
+pub struct UserFiles {
+    /// Section [glossary] et fichier glossaire
+    pub glossary: Glossary,
+    /// Règles de remplacement du fichier de règles, compilées une seule fois
+    pub rules: RuleSet,
+}
+
+impl UserFiles {
+    /// Charge les fichiers configurés, ou ceux de `config_dir` par défaut.
+    pub fn load(config: &AppConfig, config_dir: Option<&Path>) -> Self {
+        let glossary = Glossary::load(&config.glossary, config_dir).unwrap_or_else(|e| {
+            eprintln!("Warning: {}; glossary file ignored", e);
+            Glossary::from_config(&config.glossary)
+        });
+        let rules = rules::load_rules(&config.postprocess, config_dir).unwrap_or_else(|e| {
+            eprintln!("Warning: {}; replacement rules ignored", e);
+            RuleSet::default()
+        });
+        Self { glossary, rules }
+    }
+}
+
+impl TranscriptionOptions {
+    /// Résout les paramètres effectifs: requête > profil > configuration.
+    ///
+    /// # Errors
+    /// - `ConfigurationError` si le profil demandé n'existe pas, ou si le
+    ///   profil référence un groupe de glossaire inconnu
+    pub fn resolve(
+        config: &AppConfig,
+        files: &UserFiles,
+        request: &TranscriptionRequest,
+    ) -> Result<Self, AppError> {
+        let profile = match request.profile.as_ref().or(config.active_profile.as_ref()) {
+            Some(name) => Some(config.profile(name)?),
+            None => None,
+        };
+
+        let language = request
+            .language
+            .as_ref()
+            .or_else(|| profile.and_then(|p| p.language.as_ref()))
+            .unwrap_or(&config.transcription.language);
+
+        let translate = request
+            .translate
+            .or_else(|| profile.and_then(|p| p.translate))
+            .unwrap_or(config.transcription.translate);
+
+        let diarize = request
+            .diarize
+            .or_else(|| profile.and_then(|p| p.diarize))
+            .unwrap_or(config.transcription.diarize);
+
+        let initial_prompt = request
+            .initial_prompt
+            .clone()
+            .or_else(|| profile.and_then(|p| p.initial_prompt.clone()));
+
+        let postprocess = match profile {
+            Some(p) => config.postprocess.with_overrides(&p.postprocess),
+            None => config.postprocess.clone(),
+        };
+
+        let rules = if postprocess.enabled && postprocess.apply_rules {
+            files.rules.clone()
+        } else {
+            RuleSet::default()
+        };
+
+        let glossary_groups = profile.and_then(|p| p.glossary_groups.as_deref());
+        let vocabulary = files.glossary.select(glossary_groups)?;
+
+        Ok(Self {
+            language: normalize_language(language),
+            translate,
+            diarize,
+            initial_prompt,
+            vocabulary,
+            n_threads: config.transcription.n_threads,
+            decoding: config.transcription.decoding.effective(),
+            chunking: config.transcription.chunking.clone(),
+            filter: config.transcription.filter.clone(),
+            model: request.model.clone().or_else(|| config.model.name.clone()),
+            postprocess,
+            rules,
+            voice_commands: config.note.voice_commands,
+        })
+    }
+}
+
+/// Normalise un code langue: minuscules, "auto" ou vide → `None`.
+fn normalize_language(language: &str) -> Option<String> {
+    let language = language.trim().to_lowercase();
+    if language.is_empty() || language == AUTO_LANGUAGE {
+        None
+    } else {
+        Some(language)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::loader::parse_config;
+
+    /// Résout sans lire de fichier du dossier de configuration.
+    fn resolve(
+        config: &AppConfig,
+        request: &TranscriptionRequest,
+    ) -> Result<TranscriptionOptions, AppError> {
+        TranscriptionOptions::resolve(config, &UserFiles::load(config, None), request)
+    }
+
+    fn config_with_profile() -> AppConfig {
+        parse_config(
+            r#"
+            [transcription]
+            language = "fr"
+
+            [profiles.english]
+            language = "en"
+
+            [profiles.translate]
+            translate = true
+
+            [profiles.interview]
+            diarize = true
+
+            [profiles.empty]
+
+            [profiles.reunion]
+            initial_prompt = "Réunion d'équipe."
+            glossary_groups = ["equipe"]
+
+            [glossary]
+            terms = ["Tamles"]
+
+            [glossary.groups]
+            equipe = ["Amélie"]
+            produit = ["Whisper"]
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_default_config_is_auto_detect() {
+        let options = resolve(&AppConfig::default(), &TranscriptionRequest::default()).unwrap();
+        assert_eq!(options.language, None);
+        assert!(!options.translate);
+    }
+
+    #[test]
+    fn test_config_language_applies() {
+        let options = resolve(&config_with_profile(), &TranscriptionRequest::default()).unwrap();
+        assert_eq!(options.language.as_deref(), Some("fr"));
+    }
+
+    #[test]
+    fn test_profile_overrides_config() {
+        let request = TranscriptionRequest {
+            profile: Some("english".to_string()),
+            ..Default::default()
+        };
+        let options = resolve(&config_with_profile(), &request).unwrap();
+        assert_eq!(options.language.as_deref(), Some("en"));
+    }
+
+    #[test]
+    fn test_empty_profile_falls_back_to_config() {
+        let request = TranscriptionRequest {
+            profile: Some("empty".to_string()),
+            ..Default::default()
+        };
+        let options = resolve(&config_with_profile(), &request).unwrap();
+        assert_eq!(options.language.as_deref(), Some("fr"));
+    }
+
+    #[test]
+    fn test_request_overrides_profile() {
+        let request = TranscriptionRequest {
+            profile: Some("english".to_string()),
+            language: Some("AUTO".to_string()),
+            ..Default::default()
+        };
+        let options = resolve(&config_with_profile(), &request).unwrap();
+        assert_eq!(options.language, None);
+    }
+
+    #[test]
+    fn test_translate_from_profile() {
+        let request = TranscriptionRequest {
+            profile: Some("translate".to_string()),
+            ..Default::default()
+        };
+        let options = resolve(&config_with_profile(), &request).unwrap();
+        assert!(options.translate);
+        assert_eq!(options.language.as_deref(), Some("fr"));
+    }
+
+    #[test]
+    fn test_request_disables_profile_translate() {
+        let request = TranscriptionRequest {
+            profile: Some("translate".to_string()),
+            translate: Some(false),
+            ..Default::default()
+        };
+        let options = resolve(&config_with_profile(), &request).unwrap();
+        assert!(!options.translate);
+    }
+
+    #[test]
+    fn test_diarize_from_profile_or_request() {
+        let request = TranscriptionRequest {
+            profile: Some("interview".to_string()),
+            ..Default::default()
+        };
+        let options = resolve(&config_with_profile(), &request).unwrap();
+        assert!(options.diarize);
+
+        let options = resolve(&config_with_profile(), &TranscriptionRequest::default()).unwrap();
+        assert!(!options.diarize);
+
+        let request = TranscriptionRequest {
+            diarize: Some(true),
+            ..Default::default()
+        };
+        let options = resolve(&config_with_profile(), &request).unwrap();
+        assert!(options.diarize);
+    }
+
+    #[test]
+    fn test_postprocess_overridden_by_profile() {
+        let config = parse_config(
+            r#"
+            [profiles.verbatim.postprocess]
+            remove_fillers = false
+            "#,
+        )
+        .unwrap();
+        let options = resolve(&config, &TranscriptionRequest::default()).unwrap();
+        assert!(options.postprocess.remove_fillers);
+
+        let request = TranscriptionRequest {
+            profile: Some("verbatim".to_string()),
+            ..Default::default()
+        };
+        let options = resolve(&config, &request).unwrap();
+        assert!(!options.postprocess.remove_fillers);
+        assert!(options.postprocess.fix_capitalization);
+    }
+
+    #[test]
+    fn test_vocabulary_uses_all_groups_by_default() {
+        let options = resolve(&config_with_profile(), &TranscriptionRequest::default()).unwrap();
+        assert_eq!(options.vocabulary, vec!["Tamles", "Amélie", "Whisper"]);
+        assert_eq!(options.initial_prompt, None);
+    }
+
+    #[test]
+    fn test_profile_selects_glossary_groups_and_prompt() {
+        let request = TranscriptionRequest {
+            profile: Some("reunion".to_string()),
+            ..Default::default()
+        };
+        let options = resolve(&config_with_profile(), &request).unwrap();
+        assert_eq!(options.vocabulary, vec!["Tamles", "Amélie"]);
+        assert_eq!(options.initial_prompt.as_deref(), Some("Réunion d'équipe."));
+    }
+
+    #[test]
+    fn test_model_from_request_or_config() {
+        let config = parse_config("[model]\nname = \"ggml-medium.bin\"").unwrap();
+        let options = resolve(&config, &TranscriptionRequest::default()).unwrap();
+        assert_eq!(options.model.as_deref(), Some("ggml-medium.bin"));
+
+        let request = TranscriptionRequest {
+            model: Some("ggml-base.bin".to_string()),
+            ..Default::default()
+        };
+        let options = resolve(&config, &request).unwrap();
+        assert_eq!(options.model.as_deref(), Some("ggml-base.bin"));
+    }
+
+    #[test]
+    fn test_unreadable_glossary_is_ignored() {
+        let config = parse_config(
+            r#"
+            [glossary]
+            file = "/nonexistent/glossary.txt"
+            terms = ["Tamles"]
+            "#,
+        )
+        .unwrap();
+        let options = resolve(&config, &TranscriptionRequest::default()).unwrap();
+        assert_eq!(options.vocabulary, vec!["Tamles"]);
+    }
+
+    #[test]
+    fn test_rules_loaded_at_startup_apply_when_enabled() {
+        let config = parse_config(
+            r#"
+            [postprocess]
+            rules_file = "/nonexistent/rules.toml"
+
+            [profiles.dictee.postprocess]
+            enabled = true
+            "#,
+        )
+        .unwrap();
+        let mut files = UserFiles::load(&config, None);
+        assert!(files.rules.is_empty());
+
+        files.rules = rules::parse_rules("[[rule]]\nfind = \"jo\"\nreplace = \"Joe\"").unwrap();
+        let options =
+            TranscriptionOptions::resolve(&config, &files, &TranscriptionRequest::default())
+                .unwrap();
+        assert!(options.rules.is_empty());
+
+        let request = TranscriptionRequest {
+            profile: Some("dictee".to_string()),
+            ..Default::default()
+        };
+        let options = TranscriptionOptions::resolve(&config, &files, &request).unwrap();
+        assert_eq!(options.rules, files.rules);
+    }
+
+    #[test]
+    fn test_unknown_profile_is_error() {
+        let request = TranscriptionRequest {
+            profile: Some("missing".to_string()),
+            ..Default::default()
+        };
+        let result = resolve(&config_with_profile(), &request);
+        assert!(matches!(result, Err(AppError::ConfigurationError(_))));
+    }
+}
@@ -0,0 +1,369 @@
+//! Job queue module - ordered transcription jobs
+//!
+//! Each stopped recording becomes a job with its own WAV file and ID.
+//! Jobs are transcribed one at a time, in submission order, by a single
+//! worker; queued jobs can be cancelled and a running job aborted.
+
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::Notify;
+
+use crate::audio::memory::Samples;
+use crate::error::AppError;
+use crate::transcription::options::TranscriptionOptions;
+
+/// Identifiant de tâche, unique pour la durée du processus
+pub type JobId = u64;
+
+/// Drapeau d'annulation partagé avec le callback d'abandon de whisper.cpp
+pub type CancelFlag = Arc<AtomicBool>;
+
+/// État d'une tâche active.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    Queued,
+    Running,
+}
+
+/// Vue d'une tâche pour le frontend (`list_jobs`).
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct JobInfo {
+    pub id: JobId,
+    pub status: JobStatus,
+    /// Position dans la file (0 = en cours ou prochaine)
+    pub position: usize,
+    pub audio_path: PathBuf,
+}
+
+/// Tâche de transcription.
+#[derive(Debug, Clone)]
+pub struct Job {
+    pub id: JobId,
+    /// Fichier WAV, ou identifiant `memory://` en mode confidentialité
+    pub audio_path: PathBuf,
+    /// Échantillons en mémoire ([privacy] in_memory): aucun fichier à lire ni supprimer
+    pub samples: Option<Samples>,
+    pub options: TranscriptionOptions,
+    pub cancel: CancelFlag,
+}
+
+impl Job {
+    /// Indique si l'annulation a été demandée.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.load(Ordering::Relaxed)
+    }
+}
+
+/// Résultat de `JobQueue::cancel`.
+#[derive(Debug)]
+pub enum CancelOutcome {
+    /// Tâche retirée de la file avant son démarrage
+    Removed(Box<Job>),
+    /// Tâche en cours: whisper.cpp s'arrêtera au prochain point de contrôle
+    Aborting,
+}
+
+/// File d'attente des transcriptions (FIFO, une tâche en cours au plus).
+#[derive(Debug, Default)]
+pub struct JobQueue {
+    next_id: JobId,
+    pending: VecDeque<Job>,
+    running: Option<Job>,
+}
+
+impl JobQueue {
+    /// Ajoute une tâche en fin de file.
+    ///
+    /// # Errors
+    /// - `TranscriptionFailed` si cet enregistrement est déjà en file ou en cours
+    pub fn enqueue(
+        &mut self,
+        audio_path: PathBuf,
+        options: TranscriptionOptions,
+    ) -> Result<JobId, AppError> {
+        self.enqueue_audio(audio_path, None, options)
+    }
+
+    /// Ajoute en fin de file un enregistrement gardé en mémoire.
+    ///
+    /// # Errors
+    /// Voir `enqueue`.
+    pub fn enqueue_samples(
+        &mut self,
+        id: PathBuf,
+        samples: Samples,
+        options: TranscriptionOptions,
+    ) -> Result<JobId, AppError> {
+        self.enqueue_audio(id, Some(samples), options)
+    }
+
+    fn enqueue_audio(
+        &mut self,
+        audio_path: PathBuf,
+        samples: Option<Samples>,
+        options: TranscriptionOptions,
+    ) -> Result<JobId, AppError> {
+        if self.contains_audio(&audio_path) {
+            return Err(AppError::TranscriptionFailed(format!(
+                "Enregistrement déjà en cours de transcription: {}",
+                audio_path.display()
+            )));
+        }
+
+        self.next_id += 1;
+        self.pending.push_back(Job {
+            id: self.next_id,
+            audio_path,
+            samples,
+            options,
+            cancel: Arc::new(AtomicBool::new(false)),
+        });
+        Ok(self.next_id)
+    }
+
+    /// Démarre la prochaine tâche, si aucune n'est en cours.
+    pub fn start_next(&mut self) -> Option<Job> {
+        if self.running.is_some() {
+            return None;
+        }
+        let job = self.pending.pop_front()?;
+        self.running = Some(job.clone());
+        Some(job)
+    }
+
+    /// Marque la tâche en cours comme terminée (succès, échec ou annulation).
+    pub fn finish(&mut self, id: JobId) {
+        if self.running.as_ref().is_some_and(|job| job.id == id) {
+            self.running = None;
+        }
+    }
+
+    /// Annule une tâche en file, ou demande l'arrêt de la tâche en cours.
+    ///
+    /// # Errors
+    /// - `TranscriptionFailed` si aucune tâche active ne porte cet ID
+    pub fn cancel(&mut self, id: JobId) -> Result<CancelOutcome, AppError> {
+        if let Some(job) = self.running.as_ref().filter(|job| job.id == id) {
+            job.cancel.store(true, Ordering::Relaxed);
+            return Ok(CancelOutcome::Aborting);
+        }
+
+        let index = self
+            .pending
+            .iter()
+            .position(|job| job.id == id)
+            .ok_or_else(|| {
+                AppError::TranscriptionFailed(format!("Tâche de transcription inconnue: {}", id))
+            })?;
+        let job = self.pending.remove(index).expect("index from position()");
+        Ok(CancelOutcome::Removed(Box::new(job)))
+    }
+
+    /// Tâches actives: celle en cours puis la file, dans l'ordre de traitement.
+    pub fn list(&self) -> Vec<JobInfo> {
+        let running = self.running.iter().map(|job| (job, JobStatus::Running));
+        let queued = self.pending.iter().map(|job| (job, JobStatus::Queued));
+
+        running
+            .chain(queued)
+            .enumerate()
+            .map(|(position, (job, status))| JobInfo {
+                id: job.id,
+                status,
+                position,
+                audio_path: job.audio_path.clone(),
+            })
+            .collect()
+    }
+
+    /// Position d'une tâche dans l'ordre de traitement.
+    pub fn position(&self, id: JobId) -> Option<usize> {
+        self.list().iter().position(|info| info.id == id)
+    }
+
+    /// Tâche active qui transcrit cet enregistrement.
+    pub fn find_by_audio(&self, path: &Path) -> Option<JobId> {
+        self.running
+            .iter()
+            .chain(self.pending.iter())
+            .find(|job| job.audio_path == path)
+            .map(|job| job.id)
+    }
+
+    fn contains_audio(&self, path: &Path) -> bool {
+        self.find_by_audio(path).is_some()
+    }
+}
+
+/// State managed by Tauri: the queue plus a wake-up signal for the worker.
+#[derive(Default)]
+pub struct JobQueueState {
+    pub queue: Mutex<JobQueue>,
+    notify: Notify,
+}
+
+impl JobQueueState {
+    /// Ajoute une tâche et réveille le worker.
+    ///
+    /// # Errors
+    /// Voir `JobQueue::enqueue`.
+    pub fn submit(
+        &self,
+        audio_path: PathBuf,
+        options: TranscriptionOptions,
+    ) -> Result<JobId, AppError> {
+        let id = self
+            .queue
+            .lock()
+            .expect("Job queue lock poisoned")
+            .enqueue(audio_path, options)?;
+        self.notify.notify_one();
+        Ok(id)
+    }
+
+    /// Ajoute une tâche sur un enregistrement en mémoire et réveille le worker.
+    ///
+    /// # Errors
+    /// Voir `JobQueue::enqueue`.
+    pub fn submit_samples(
+        &self,
+        id: PathBuf,
+        samples: Samples,
+        options: TranscriptionOptions,
+    ) -> Result<JobId, AppError> {
+        let job_id = self
+            .queue
+            .lock()
+            .expect("Job queue lock poisoned")
+            .enqueue_samples(id, samples, options)?;
+        self.notify.notify_one();
+        Ok(job_id)
+    }
+
+    /// Attend puis démarre la prochaine tâche (utilisé par le worker unique).
+    pub async fn next_job(&self) -> Job {
+        loop {
+            if let Some(job) = self
+                .queue
+                .lock()
+                .expect("Job queue lock poisoned")
+                .start_next()
+            {
+                return job;
+            }
+            self.notify.notified().await;
+        }
+    }
+
+    /// Marque une tâche comme terminée.
+    pub fn finish(&self, id: JobId) {
+        self.queue
+            .lock()
+            .expect("Job queue lock poisoned")
+            .finish(id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AppConfig;
+    use crate::transcription::options::{TranscriptionRequest, UserFiles};
+
+    fn options() -> TranscriptionOptions {
+        TranscriptionOptions::resolve(
+            &AppConfig::default(),
+            &UserFiles::default(),
+            &TranscriptionRequest::default(),
+        )
+        .unwrap()
+    }
+
+    fn queue_with(paths: &[&str]) -> JobQueue {
+        let mut queue = JobQueue::default();
+        for path in paths {
+            queue.enqueue(PathBuf::from(path), options()).unwrap();
+        }
+        queue
+    }
+
+    #[test]
+    fn test_jobs_run_in_submission_order() {
+        let mut queue = queue_with(&["/tmp/a.wav", "/tmp/b.wav"]);
+
+        let first = queue.start_next().unwrap();
+        assert_eq!(first.id, 1);
+        assert!(queue.start_next().is_none(), "Only one job runs at a time");
+
+        queue.finish(first.id);
+        let second = queue.start_next().unwrap();
+        assert_eq!(second.id, 2);
+        assert_eq!(second.audio_path, PathBuf::from("/tmp/b.wav"));
+    }
+
+    #[test]
+    fn test_same_recording_cannot_be_queued_twice() {
+        let mut queue = queue_with(&["/tmp/a.wav"]);
+        let result = queue.enqueue(PathBuf::from("/tmp/a.wav"), options());
+        assert!(matches!(result, Err(AppError::TranscriptionFailed(_))));
+    }
+
+    #[test]
+    fn test_list_jobs_running_first() {
+        let mut queue = queue_with(&["/tmp/a.wav", "/tmp/b.wav", "/tmp/c.wav"]);
+        queue.start_next();
+
+        let jobs = queue.list();
+        assert_eq!(jobs.len(), 3);
+        assert_eq!(jobs[0].status, JobStatus::Running);
+        assert_eq!(jobs[2].id, 3);
+        assert_eq!(jobs[2].position, 2);
+        assert_eq!(queue.position(2), Some(1));
+    }
+
+    #[test]
+    fn test_cancel_queued_job_removes_it() {
+        let mut queue = queue_with(&["/tmp/a.wav", "/tmp/b.wav"]);
+
+        match queue.cancel(2).unwrap() {
+            CancelOutcome::Removed(job) => assert_eq!(job.audio_path, PathBuf::from("/tmp/b.wav")),
+            other => panic!("Expected Removed, got {:?}", other),
+        }
+        assert_eq!(queue.list().len(), 1);
+    }
+
+    #[test]
+    fn test_cancel_running_job_sets_flag() {
+        let mut queue = queue_with(&["/tmp/a.wav"]);
+        let job = queue.start_next().unwrap();
+
+        assert!(matches!(queue.cancel(job.id), Ok(CancelOutcome::Aborting)));
+        assert!(job.is_cancelled(), "Worker's copy shares the cancel flag");
+    }
+
+    #[test]
+    fn test_in_memory_job_carries_samples() {
+        let mut queue = JobQueue::default();
+        let id = PathBuf::from("memory://recording-1-0");
+        queue
+            .enqueue_samples(id.clone(), vec![0.5; 4].into(), options())
+            .unwrap();
+
+        let job = queue.start_next().unwrap();
+        assert_eq!(job.audio_path, id);
+        assert_eq!(job.samples.as_deref().map(<[f32]>::len), Some(4));
+    }
+
+    #[test]
+    fn test_cancel_unknown_job_is_error() {
+        let mut queue = queue_with(&["/tmp/a.wav"]);
+        assert!(matches!(
+            queue.cancel(42),
+            Err(AppError::TranscriptionFailed(_))
+        ));
+    }
+}
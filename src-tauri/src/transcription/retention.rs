@@ -0,0 +1,135 @@
+//! Retention module - last recording kept for re-transcription
+//!
+//! Opt-in ([privacy] retention_secs): after a successful transcription the
+//! worker hands the job's audio here instead of deleting it, so a bad result
+//! can be redone with another model, language or prompt. Only the last
+//! recording is kept; it is deleted when the user accepts the result, when
+//! the window expires or when a newer recording replaces it (NFR-SEC-3).
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::transcription::queue::{Job, JobId};
+
+/// Enregistrement conservé après sa transcription.
+#[derive(Debug)]
+struct RetainedRecording {
+    job: Job,
+    expires_at: Instant,
+}
+
+/// State managed by Tauri: the last transcribed recording, if retained.
+#[derive(Debug, Default)]
+pub struct RetentionState {
+    last: Mutex<Option<RetainedRecording>>,
+}
+
+impl RetentionState {
+    /// Garde l'audio d'une tâche réussie pendant `window`.
+    ///
+    /// Retourne l'enregistrement remplacé, dont l'audio doit être supprimé.
+    pub fn keep(&self, job: Job, window: Duration) -> Option<Job> {
+        let retained = RetainedRecording {
+            job,
+            expires_at: Instant::now() + window,
+        };
+        self.last
+            .lock()
+            .expect("Retention lock poisoned")
+            .replace(retained)
+            .map(|previous| previous.job)
+    }
+
+    /// Reprend l'enregistrement d'une tâche (re-transcription ou acceptation).
+    ///
+    /// `None` si cette tâche n'est pas la dernière conservée ou si sa fenêtre a expiré.
+    pub fn take(&self, job_id: JobId) -> Option<Job> {
+        let mut last = self.last.lock().expect("Retention lock poisoned");
+        let current = last
+            .as_ref()
+            .is_some_and(|r| r.job.id == job_id && Instant::now() < r.expires_at);
+        if current {
+            last.take().map(|r| r.job)
+        } else {
+            None
+        }
+    }
+
+    /// Retire l'enregistrement conservé si sa fenêtre a expiré à `now`.
+    pub fn take_expired(&self, now: Instant) -> Option<Job> {
+        let mut last = self.last.lock().expect("Retention lock poisoned");
+        if last.as_ref().is_some_and(|r| now >= r.expires_at) {
+            last.take().map(|r| r.job)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AppConfig;
+    use crate::transcription::options::{TranscriptionOptions, TranscriptionRequest, UserFiles};
+    use crate::transcription::queue::JobQueue;
+    use std::path::PathBuf;
+
+    const WINDOW: Duration = Duration::from_secs(60);
+
+    fn jobs(count: usize) -> Vec<Job> {
+        let options = TranscriptionOptions::resolve(
+            &AppConfig::default(),
+            &UserFiles::default(),
+            &TranscriptionRequest::default(),
+        )
+        .unwrap();
+        let mut queue = JobQueue::default();
+        (0..count)
+            .map(|i| {
+                let id = queue
+                    .enqueue(PathBuf::from(format!("/tmp/{}.wav", i)), options.clone())
+                    .unwrap();
+                let job = queue.start_next().unwrap();
+                queue.finish(id);
+                job
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_take_returns_retained_job_once() {
+        let job = jobs(1).remove(0);
+        let retention = RetentionState::default();
+        assert!(retention.keep(job.clone(), WINDOW).is_none());
+
+        assert!(retention.take(job.id + 1).is_none(), "Unknown job");
+        assert_eq!(retention.take(job.id).unwrap().audio_path, job.audio_path);
+        assert!(retention.take(job.id).is_none());
+    }
+
+    #[test]
+    fn test_newer_recording_replaces_previous() {
+        let jobs = jobs(2);
+        let retention = RetentionState::default();
+        retention.keep(jobs[0].clone(), WINDOW);
+
+        let replaced = retention.keep(jobs[1].clone(), WINDOW).unwrap();
+        assert_eq!(replaced.id, jobs[0].id);
+        assert!(retention.take(jobs[0].id).is_none());
+        assert!(retention.take(jobs[1].id).is_some());
+    }
+
+    #[test]
+    fn test_expired_recording_cannot_be_taken() {
+        let job = jobs(1).remove(0);
+        let retention = RetentionState::default();
+        retention.keep(job.clone(), WINDOW);
+
+        assert!(retention.take_expired(Instant::now()).is_none());
+        assert!(retention.take_expired(Instant::now() + WINDOW).is_some());
+
+        retention.keep(job.clone(), Duration::ZERO);
+        assert!(retention.take(job.id).is_none(), "Window already over");
+        assert!(retention.take_expired(Instant::now()).is_some());
+    }
+}
@@ -0,0 +1,602 @@
+//! Transcript module - structured transcription result
+//!
+//! Keeps Whisper segments with their timing instead of a flat string,
+//! as a basis for subtitles, meeting navigation and segment playback.
+//! Segments carry word-level timestamps and confidence, grouped from
+//! Whisper tokens, for low-confidence highlighting and text/audio alignment.
+//! With tinydiarize models, segments carry their speaker (A or B) and the
+//! full text starts a new line at each speaker turn; the "Speaker A:" labels
+//! are only rendered for display and export (`Transcript::labelled_text`).
+
+use serde::{Deserialize, Serialize};
+
+use crate::transcription::filter::RemovedSegment;
+
+/// Langue détectée automatiquement par Whisper.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DetectedLanguage {
+    /// Code ISO 639-1 (ex: "fr")
+    pub code: String,
+    /// Probabilité attribuée par Whisper à cette langue (0.0-1.0)
+    pub probability: f32,
+}
+
+/// Token Whisper horodaté, tel qu'extrait de l'état whisper.cpp.
+///
+/// Les tokens BPE peuvent couper un caractère UTF-8 en deux: le texte est
+/// conservé en octets et décodé seulement une fois regroupé en mots.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenTiming {
+    /// Octets du token (commence par un espace en début de mot)
+    pub bytes: Vec<u8>,
+    /// Début du token en millisecondes
+    pub start_ms: i64,
+    /// Fin du token en millisecondes
+    pub end_ms: i64,
+    /// Probabilité du token (0.0-1.0)
+    pub probability: f32,
+}
+
+/// Mot horodaté avec son score de confiance.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Word {
+    /// Texte du mot, ponctuation attenante incluse
+    pub text: String,
+    /// Début du mot en millisecondes
+    pub start_ms: i64,
+    /// Fin du mot en millisecondes
+    pub end_ms: i64,
+    /// Confiance: moyenne des probabilités de ses tokens (0.0-1.0)
+    pub probability: f32,
+}
+
+/// Locuteur d'un entretien à deux, alterné à chaque tour de parole détecté.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Speaker {
+    A,
+    B,
+}
+
+impl Speaker {
+    /// L'autre locuteur.
+    pub fn other(self) -> Self {
+        match self {
+            Speaker::A => Speaker::B,
+            Speaker::B => Speaker::A,
+        }
+    }
+
+    /// Libellé affiché devant chaque tour de parole ("Speaker A").
+    pub fn label(self) -> &'static str {
+        match self {
+            Speaker::A => "Speaker A",
+            Speaker::B => "Speaker B",
+        }
+    }
+}
+
+/// Segment de transcription horodaté.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Segment {
+    /// Début du segment en millisecondes depuis le début de l'audio
+    pub start_ms: i64,
+    /// Fin du segment en millisecondes
+    pub end_ms: i64,
+    /// Texte du segment (sans espaces de bordure)
+    pub text: String,
+    /// Probabilité que le segment ne contienne pas de parole (0.0-1.0)
+    pub no_speech_probability: f32,
+    /// Mots horodatés du segment
+    pub words: Vec<Word>,
+    /// Autres lectures proposées par des passes de décodage supplémentaires
+    pub alternatives: Vec<String>,
+    /// Locuteur, renseigné uniquement quand la détection des tours est active
+    #[serde(default)]
+    pub speaker: Option<Speaker>,
+    /// Le locuteur change après ce segment (`next_segment_speaker_turn` de tinydiarize)
+    #[serde(default)]
+    pub speaker_turn: bool,
+}
+
+/// Résultat structuré d'une transcription.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Transcript {
+    /// Texte complet, post-traité ([postprocess])
+    pub text: String,
+    /// Texte brut de Whisper (segments joints par des espaces, une ligne par tour de parole, sans libellé)
+    pub raw_text: String,
+    /// Segments dans l'ordre chronologique
+    pub segments: Vec<Segment>,
+    /// Durée de l'audio transcrit en millisecondes
+    pub duration_ms: i64,
+    /// Langue détectée, renseignée uniquement en mode auto-détection
+    pub detected_language: Option<DetectedLanguage>,
+    /// Langue parlée dans l'audio (forcée ou détectée)
+    pub source_language: Option<String>,
+    /// Le texte a été traduit vers l'anglais par Whisper
+    pub translated: bool,
+    /// Segments retirés par le filtre d'hallucinations
+    pub removed: Vec<RemovedSegment>,
+}
+
+impl Transcript {
+    /// Construit un transcript à partir de ses segments; le texte complet en est dérivé.
+    pub fn from_segments(segments: Vec<Segment>, duration_ms: i64) -> Self {
+        let text = join_segments(&segments);
+        Self {
+            raw_text: text.clone(),
+            text,
+            segments,
+            duration_ms,
+            detected_language: None,
+            source_language: None,
+            translated: false,
+            removed: Vec::new(),
+        }
+    }
+
+    /// Texte à afficher ou exporter: chaque tour de parole précédé de son
+    /// locuteur ("Speaker A: ..."). Sans locuteurs, le texte tel quel.
+    ///
+    /// Le post-traitement garde une ligne par tour; si ce n'est plus le cas
+    /// (retour à la ligne dicté), les tours ne peuvent plus être rattachés aux
+    /// lignes: `None`, à l'appelant de signaler la perte des libellés.
+    pub fn labelled_text(&self) -> Option<String> {
+        let speakers: Vec<Speaker> = speaker_turns(&self.segments)
+            .into_iter()
+            .filter_map(|(speaker, _)| speaker)
+            .collect();
+        if speakers.is_empty() {
+            return Some(self.text.clone());
+        }
+        let lines: Vec<&str> = self.text.lines().collect();
+        if speakers.len() != lines.len() {
+            return None;
+        }
+
+        let labelled = speakers
+            .iter()
+            .zip(lines)
+            .map(|(speaker, line)| format!("{}: {}", speaker.label(), line))
+            .collect::<Vec<_>>()
+            .join("\n");
+        Some(labelled)
+    }
+
+    /// Itère sur tous les mots du transcript, dans l'ordre chronologique.
+    pub fn words(&self) -> impl Iterator<Item = &Word> {
+        self.segments.iter().flat_map(|s| s.words.iter())
+    }
+
+    /// Mots dont la confiance est inférieure au seuil, à faire relire.
+    pub fn low_confidence_words(&self, threshold: f32) -> impl Iterator<Item = &Word> {
+        self.words().filter(move |w| w.probability < threshold)
+    }
+}
+
+/// Regroupe des tokens Whisper en mots.
+///
+/// Un token commençant par un espace ouvre un nouveau mot; un token de pure
+/// ponctuation reste attaché au mot précédent. La confiance d'un mot est la
+/// moyenne des probabilités de ses tokens.
+pub fn group_tokens_into_words(tokens: &[TokenTiming]) -> Vec<Word> {
+    let mut words = Vec::new();
+    let mut current: Option<PendingWord> = None;
+
+    for token in tokens {
+        if token.bytes.is_empty() {
+            continue;
+        }
+
+        let starts_word = token.bytes[0].is_ascii_whitespace() && !is_punctuation(&token.bytes);
+
+        match current {
+            Some(ref mut word) if !starts_word => word.push(token),
+            _ => {
+                words.extend(current.take().and_then(PendingWord::finish));
+                current = Some(PendingWord::new(token));
+            }
+        }
+    }
+
+    words.extend(current.and_then(PendingWord::finish));
+    words
+}
+
+/// Mot en cours d'accumulation pendant le regroupement des tokens.
+struct PendingWord {
+    bytes: Vec<u8>,
+    start_ms: i64,
+    end_ms: i64,
+    probabilities: Vec<f32>,
+}
+
+impl PendingWord {
+    fn new(token: &TokenTiming) -> Self {
+        Self {
+            bytes: token.bytes.clone(),
+            start_ms: token.start_ms,
+            end_ms: token.end_ms,
+            probabilities: vec![token.probability],
+        }
+    }
+
+    fn push(&mut self, token: &TokenTiming) {
+        self.bytes.extend_from_slice(&token.bytes);
+        self.end_ms = token.end_ms;
+        self.probabilities.push(token.probability);
+    }
+
+    /// Décode le mot accumulé; `None` s'il est vide après trim.
+    fn finish(self) -> Option<Word> {
+        let text = String::from_utf8_lossy(&self.bytes).trim().to_string();
+        if text.is_empty() {
+            return None;
+        }
+        Some(Word {
+            text,
+            start_ms: self.start_ms,
+            end_ms: self.end_ms,
+            probability: self.probabilities.iter().sum::<f32>() / self.probabilities.len() as f32,
+        })
+    }
+}
+
+/// Rattache les segments d'une passe alternative aux segments principaux.
+///
+/// Les frontières de segments varient d'une passe à l'autre: chaque candidat est
+/// rattaché au segment principal qui contient son milieu. Les lectures identiques
+/// au texte principal (à la casse et aux espaces près) ou déjà connues sont ignorées.
+pub fn attach_alternatives(segments: &mut [Segment], candidates: &[Segment]) {
+    for segment in segments.iter_mut() {
+        let reading = candidates
+            .iter()
+            .filter(|c| {
+                let middle = (c.start_ms + c.end_ms) / 2;
+                middle >= segment.start_ms && middle < segment.end_ms
+            })
+            .map(|c| c.text.as_str())
+            .filter(|t| !t.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if reading.is_empty() {
+            continue;
+        }
+
+        let key = normalize_reading(&reading);
+        let known = normalize_reading(&segment.text) == key
+            || segment
+                .alternatives
+                .iter()
+                .any(|a| normalize_reading(a) == key);
+        if !known {
+            segment.alternatives.push(reading);
+        }
+    }
+}
+
+/// Attribue les locuteurs à partir des tours de parole détectés.
+///
+/// `turns[i]` indique que le locuteur change après le segment `i`
+/// (`next_segment_speaker_turn` de tinydiarize).
+pub fn assign_speakers(segments: &mut [Segment], turns: &[bool], first: Speaker) {
+    let mut speaker = first;
+    for (segment, turn) in segments.iter_mut().zip(turns) {
+        segment.speaker = Some(speaker);
+        segment.speaker_turn = *turn;
+        if *turn {
+            speaker = speaker.other();
+        }
+    }
+}
+
+/// Raccorde les locuteurs d'une fenêtre à ceux de la précédente.
+///
+/// Chaque fenêtre est étiquetée à partir de Speaker A: elle commence avec le
+/// dernier locuteur de la précédente, ou avec l'autre si son dernier segment
+/// se terminait sur un tour de parole.
+pub fn continue_speakers(segments: &mut [Segment], previous: Option<&Segment>) {
+    let Some(last) = previous else {
+        return;
+    };
+    let next = match last.speaker {
+        Some(speaker) if last.speaker_turn => speaker.other(),
+        Some(speaker) => speaker,
+        None => return,
+    };
+    if next == Speaker::A {
+        return;
+    }
+    for segment in segments.iter_mut() {
+        segment.speaker = segment.speaker.map(Speaker::other);
+    }
+}
+
+/// Forme de comparaison d'une lecture: minuscules, espaces simples.
+fn normalize_reading(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Indique si un token ne contient que de la ponctuation (espaces ignorés).
+fn is_punctuation(bytes: &[u8]) -> bool {
+    let trimmed = bytes.trim_ascii();
+    !trimmed.is_empty() && trimmed.iter().all(u8::is_ascii_punctuation)
+}
+
+/// Regroupe les segments non vides par tour de parole (un seul tour sans locuteurs).
+fn speaker_turns(segments: &[Segment]) -> Vec<(Option<Speaker>, Vec<&str>)> {
+    let mut turns: Vec<(Option<Speaker>, Vec<&str>)> = Vec::new();
+    for segment in segments.iter().filter(|s| !s.text.is_empty()) {
+        match turns.last_mut() {
+            Some((speaker, texts)) if *speaker == segment.speaker => texts.push(&segment.text),
+            _ => turns.push((segment.speaker, vec![&segment.text])),
+        }
+    }
+    turns
+}
+
+/// Joint le texte des segments non vides par un espace.
+///
+/// Avec des locuteurs, chaque tour de parole ouvre une nouvelle ligne.
+fn join_segments(segments: &[Segment]) -> String {
+    speaker_turns(segments)
+        .into_iter()
+        .map(|(_, texts)| texts.join(" "))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Convertit un timestamp Whisper (centisecondes) en millisecondes.
+pub fn centiseconds_to_ms(timestamp: i64) -> i64 {
+    timestamp * 10
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(start_ms: i64, end_ms: i64, text: &str) -> Segment {
+        Segment {
+            start_ms,
+            end_ms,
+            text: text.to_string(),
+            no_speech_probability: 0.01,
+            words: Vec::new(),
+            alternatives: Vec::new(),
+            speaker: None,
+            speaker_turn: false,
+        }
+    }
+
+    fn token(text: &str, start_ms: i64, probability: f32) -> TokenTiming {
+        TokenTiming {
+            bytes: text.as_bytes().to_vec(),
+            start_ms,
+            end_ms: start_ms + 100,
+            probability,
+        }
+    }
+
+    #[test]
+    fn test_group_tokens_into_words() {
+        let tokens = vec![
+            token(" Bon", 0, 0.9),
+            token("jour", 100, 0.7),
+            token(" à", 200, 0.95),
+            token(" tous", 300, 0.8),
+            token(".", 400, 0.99),
+        ];
+
+        let words = group_tokens_into_words(&tokens);
+
+        assert_eq!(words.len(), 3);
+        assert_eq!(words[0].text, "Bonjour");
+        assert_eq!(words[0].start_ms, 0);
+        assert_eq!(words[0].end_ms, 200);
+        assert!((words[0].probability - 0.8).abs() < 1e-6);
+        assert_eq!(words[2].text, "tous.");
+        assert_eq!(words[2].end_ms, 500);
+    }
+
+    #[test]
+    fn test_group_tokens_rejoins_split_utf8() {
+        // "é" (0xC3 0xA9) coupé entre deux tokens BPE
+        let tokens = vec![
+            TokenTiming {
+                bytes: vec![b' ', b'r', 0xC3],
+                start_ms: 0,
+                end_ms: 100,
+                probability: 0.9,
+            },
+            TokenTiming {
+                bytes: vec![0xA9, b'u', b'n', b'i', b'o', b'n'],
+                start_ms: 100,
+                end_ms: 200,
+                probability: 0.9,
+            },
+        ];
+
+        let words = group_tokens_into_words(&tokens);
+        assert_eq!(words.len(), 1);
+        assert_eq!(words[0].text, "réunion");
+    }
+
+    #[test]
+    fn test_group_tokens_empty() {
+        assert!(group_tokens_into_words(&[]).is_empty());
+        assert!(group_tokens_into_words(&[token(" ", 0, 0.5)]).is_empty());
+    }
+
+    #[test]
+    fn test_low_confidence_words() {
+        let mut seg = segment(0, 500, "Bonjour Tamles");
+        seg.words =
+            group_tokens_into_words(&[token(" Bonjour", 0, 0.95), token(" Tamles", 100, 0.3)]);
+        let transcript = Transcript::from_segments(vec![seg], 500);
+
+        let doubtful: Vec<_> = transcript.low_confidence_words(0.5).collect();
+        assert_eq!(doubtful.len(), 1);
+        assert_eq!(doubtful[0].text, "Tamles");
+        assert_eq!(transcript.words().count(), 2);
+    }
+
+    #[test]
+    fn test_from_segments_joins_text() {
+        let transcript = Transcript::from_segments(
+            vec![
+                segment(0, 1500, "Bonjour à tous."),
+                segment(1500, 1600, ""),
+                segment(1600, 4000, "On commence la réunion."),
+            ],
+            4000,
+        );
+
+        assert_eq!(transcript.text, "Bonjour à tous. On commence la réunion.");
+        assert_eq!(transcript.segments.len(), 3);
+        assert_eq!(transcript.duration_ms, 4000);
+        assert!(!transcript.translated);
+    }
+
+    #[test]
+    fn test_from_no_segments_is_empty() {
+        let transcript = Transcript::from_segments(Vec::new(), 0);
+        assert!(transcript.text.is_empty());
+    }
+
+    #[test]
+    fn test_attach_alternatives_by_midpoint() {
+        let mut segments = vec![
+            segment(0, 2000, "Je vais à Lyon."),
+            segment(2000, 4000, "Rendez-vous demain."),
+        ];
+        let candidates = vec![
+            segment(0, 1000, "Je vais"),
+            segment(1000, 2100, "à Lion."),
+            segment(2100, 4000, "rendez-vous  demain."),
+        ];
+
+        attach_alternatives(&mut segments, &candidates);
+
+        assert_eq!(segments[0].alternatives, vec!["Je vais à Lion."]);
+        assert!(
+            segments[1].alternatives.is_empty(),
+            "Same reading modulo case/spaces is not an alternative"
+        );
+    }
+
+    #[test]
+    fn test_attach_alternatives_deduplicates() {
+        let mut segments = vec![segment(0, 2000, "Je vais à Lyon.")];
+        let candidates = vec![segment(0, 2000, "Je vais à Lion.")];
+
+        attach_alternatives(&mut segments, &candidates);
+        attach_alternatives(&mut segments, &candidates);
+
+        assert_eq!(segments[0].alternatives.len(), 1);
+    }
+
+    #[test]
+    fn test_assign_speakers_alternates_on_turns() {
+        let mut segments = vec![
+            segment(0, 1000, "Bonjour, merci d'être là."),
+            segment(1000, 2000, "Avec plaisir."),
+            segment(2000, 3000, "C'est normal."),
+            segment(3000, 4000, "Première question."),
+        ];
+
+        assign_speakers(&mut segments, &[true, false, true, false], Speaker::A);
+
+        let speakers: Vec<_> = segments.iter().map(|s| s.speaker).collect();
+        assert_eq!(
+            speakers,
+            vec![
+                Some(Speaker::A),
+                Some(Speaker::B),
+                Some(Speaker::B),
+                Some(Speaker::A)
+            ]
+        );
+
+        let mut transcript = Transcript::from_segments(segments, 4000);
+        assert_eq!(
+            transcript.text,
+            "Bonjour, merci d'être là.\nAvec plaisir. C'est normal.\nPremière question.",
+            "Labels stay out of the text"
+        );
+        transcript.text = transcript.text.replace("plaisir", "grand plaisir");
+        assert_eq!(
+            transcript.labelled_text().unwrap(),
+            "Speaker A: Bonjour, merci d'être là.\n\
+             Speaker B: Avec grand plaisir. C'est normal.\n\
+             Speaker A: Première question."
+        );
+
+        // Une ligne ajoutée au post-traitement: plus de correspondance ligne / tour
+        transcript.text.push_str("\nPS");
+        assert_eq!(transcript.labelled_text(), None);
+    }
+
+    #[test]
+    fn test_continue_speakers_follows_previous_window() {
+        let window = || {
+            let mut segments = vec![segment(0, 1000, "Oui."), segment(1000, 2000, "Bien.")];
+            assign_speakers(&mut segments, &[true, false], Speaker::A);
+            segments
+        };
+        let previous = |speaker, speaker_turn| Segment {
+            speaker: Some(speaker),
+            speaker_turn,
+            ..segment(0, 1000, "Alors ?")
+        };
+
+        let mut segments = window();
+        continue_speakers(&mut segments, Some(&previous(Speaker::A, false)));
+        assert_eq!(segments[0].speaker, Some(Speaker::A));
+
+        let mut segments = window();
+        continue_speakers(&mut segments, Some(&previous(Speaker::B, false)));
+        assert_eq!(segments[0].speaker, Some(Speaker::B));
+        assert_eq!(segments[1].speaker, Some(Speaker::A));
+
+        // La fenêtre précédente finissait sur un tour de parole: l'autre locuteur reprend
+        let mut segments = window();
+        continue_speakers(&mut segments, Some(&previous(Speaker::A, true)));
+        assert_eq!(segments[0].speaker, Some(Speaker::B));
+
+        let mut segments = window();
+        continue_speakers(&mut segments, Some(&previous(Speaker::B, true)));
+        assert_eq!(segments[0].speaker, Some(Speaker::A));
+    }
+
+    #[test]
+    fn test_centiseconds_to_ms() {
+        assert_eq!(centiseconds_to_ms(0), 0);
+        assert_eq!(centiseconds_to_ms(150), 1500);
+    }
+
+    #[test]
+    fn test_detected_language_serialization() {
+        let detected = DetectedLanguage {
+            code: "fr".to_string(),
+            probability: 0.5,
+        };
+        let json = serde_json::to_string(&detected).unwrap();
+        assert_eq!(json, r#"{"code":"fr","probability":0.5}"#);
+    }
+
+    #[test]
+    fn test_segment_serialization() {
+        let json = serde_json::to_value(segment(0, 1500, "Bonjour")).unwrap();
+        assert_eq!(json["start_ms"], 0);
+        assert_eq!(json["end_ms"], 1500);
+        assert_eq!(json["text"], "Bonjour");
+        assert!(json["no_speech_probability"].is_number());
+        assert!(json["words"].is_array());
+        assert!(json["alternatives"].is_array());
+        assert!(json["speaker"].is_null());
+    }
+}
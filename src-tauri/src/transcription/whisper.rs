@@ -4,16 +4,46 @@
 //! 100% local processing - no cloud fallback (NFR-SEC-1).
 
 use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 use whisper_rs::{FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters};
 
+use crate::config::loader::{DecodingConfig, DecodingStrategy};
 use crate::error::AppError;
+use crate::transcription::filter::filter_segments;
+use crate::transcription::glossary::{build_initial_prompt, MAX_PROMPT_TOKENS};
+use crate::transcription::models::{
+    find_installed_model, get_models_dir, list_installed_models, validate_model_file,
+};
+use crate::transcription::options::TranscriptionOptions;
+use crate::transcription::queue::CancelFlag;
+use crate::transcription::transcript::{
+    assign_speakers, attach_alternatives, centiseconds_to_ms, group_tokens_into_words,
+    DetectedLanguage, Segment, Speaker, TokenTiming, Transcript,
+};
+
+/// Message d'erreur d'une transcription interrompue par `cancel_job`
+pub const TRANSCRIPTION_CANCELLED: &str = "Transcription annulée";
+
+/// Température de la première passe alternative (n-best)
+const ALTERNATIVE_BASE_TEMPERATURE: f32 = 0.4;
+
+/// Écart de température entre deux passes alternatives
+const ALTERNATIVE_TEMPERATURE_STEP: f32 = 0.2;
+
+/// Modèle utilisé quand [model] name n'est pas renseigné
+pub const DEFAULT_MODEL_NAME: &str = "ggml-large-v3.bin";
 
 /// Wrapper around WhisperContext for managed state.
 /// The context is thread-safe and can be shared across async tasks.
 pub struct WhisperModel {
     context: WhisperContext,
+    /// Fichier chargé, pour savoir si une tâche demande un autre modèle
+    path: PathBuf,
+    /// Fin de la dernière utilisation (ou chargement), pour le déchargement après inactivité
+    last_used: Instant,
 }
 
 impl WhisperModel {
@@ -67,7 +97,16 @@ impl WhisperModel {
             model_path.display()
         );
 
-        Ok(Self { context })
+        Ok(Self {
+            context,
+            path: model_path.to_path_buf(),
+            last_used: Instant::now(),
+        })
+    }
+
+    /// Fichier dont le modèle a été chargé.
+    pub fn path(&self) -> &Path {
+        &self.path
     }
 
     /// Returns a reference to the underlying WhisperContext.
@@ -75,6 +114,16 @@ impl WhisperModel {
     pub fn context(&self) -> &WhisperContext {
         &self.context
     }
+
+    /// Marque le modèle comme utilisé maintenant (repart le délai d'inactivité).
+    pub fn touch(&mut self) {
+        self.last_used = Instant::now();
+    }
+
+    /// Temps écoulé depuis la dernière utilisation.
+    pub fn idle_duration(&self) -> Duration {
+        self.last_used.elapsed()
+    }
 }
 
 /// State managed by Tauri for the Whisper model.
@@ -91,22 +140,108 @@ impl Default for WhisperState {
     }
 }
 
-/// Returns the expected path for the Whisper model.
+impl WhisperState {
+    /// Décharge le modèle s'il n'a pas servi depuis `timeout`.
+    ///
+    /// Ne bloque jamais: si le modèle est verrouillé (transcription en cours),
+    /// il est considéré comme utilisé. Le prochain `start_transcription` le
+    /// recharge à la demande.
+    ///
+    /// # Returns
+    /// `true` si le modèle a été déchargé
+    pub fn unload_if_idle(&self, timeout: Duration) -> bool {
+        let Ok(mut guard) = self.model.try_lock() else {
+            return false;
+        };
+
+        if guard
+            .as_ref()
+            .is_some_and(|model| model.idle_duration() >= timeout)
+        {
+            // Drop du WhisperContext: libère la mémoire du modèle (~3GB pour large-v3)
+            *guard = None;
+            println!("Whisper model unloaded after {}s idle", timeout.as_secs());
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Charge le modèle `model_path` dans `slot` s'il n'y est pas déjà.
+///
+/// Appelé avec le verrou de `WhisperState::model` tenu, pour qu'un seul
+/// chargement ait lieu (préchargement au démarrage ou première transcription).
+/// Un autre modèle en mémoire est libéré avant le chargement: jamais deux
+/// modèles à la fois. Le nouveau fichier est vérifié avant, pour qu'un modèle
+/// invalide ne fasse pas perdre celui en place.
+///
+/// # Returns
+/// `true` si le modèle vient d'être chargé, `false` s'il l'était déjà
+///
+/// # Errors
+/// - `ModelLoadFailed` si le nouveau fichier n'est pas un modèle Whisper valide
+/// - `ModelNotFound` / `ModelLoadFailed` (voir `WhisperModel::load`)
+pub fn load_model_if_needed(
+    slot: &mut Option<WhisperModel>,
+    model_path: &Path,
+) -> Result<bool, AppError> {
+    if let Some(model) = slot.as_ref() {
+        if model.path() == model_path {
+            return Ok(false);
+        }
+        validate_model_file(model_path).map_err(|e| match e {
+            AppError::ModelImportFailed(reason) => AppError::ModelLoadFailed(reason),
+            other => other,
+        })?;
+        println!(
+            "Switching Whisper model: {} -> {}",
+            model.path().display(),
+            model_path.display()
+        );
+        *slot = None;
+    }
+    *slot = Some(WhisperModel::load(model_path)?);
+    Ok(true)
+}
+
+/// Returns the expected path for the default Whisper model.
 ///
 /// Location: ~/.local/share/vocal-note-taker/models/ggml-large-v3.bin
 ///
 /// # Errors
 /// Returns `ConfigurationError` if the system data directory cannot be determined.
 pub fn get_model_path() -> Result<PathBuf, AppError> {
-    let mut path = dirs::data_local_dir().ok_or_else(|| {
-        AppError::ConfigurationError(
-            "Impossible de déterminer le répertoire de données local".to_string(),
-        )
-    })?;
-    path.push("vocal-note-taker");
-    path.push("models");
-    path.push("ggml-large-v3.bin");
-    Ok(path)
+    Ok(get_models_dir()?.join(DEFAULT_MODEL_NAME))
+}
+
+/// Chemin du modèle à utiliser: `name` (requête ou [model] name) s'il est
+/// donné, sinon ggml-large-v3.bin, sinon le premier modèle installé (un
+/// modèle importé avec `import_model` sert alors sans configuration).
+///
+/// # Errors
+/// - `ModelNotFound` si `name` n'est pas un modèle installé
+/// - `ConfigurationError` si le répertoire de données est introuvable
+pub fn resolve_model_path(name: Option<&str>) -> Result<PathBuf, AppError> {
+    if let Some(name) = name {
+        return find_installed_model(name);
+    }
+
+    let default_path = get_model_path()?;
+    if default_path.is_file() {
+        return Ok(default_path);
+    }
+    // Sans modèle installé, le chemin par défaut sert au message d'erreur
+    match list_installed_models()?.into_iter().next() {
+        Some(model) => {
+            println!(
+                "{} not installed, using installed model {}",
+                DEFAULT_MODEL_NAME, model.name
+            );
+            Ok(model.path)
+        }
+        None => Ok(default_path),
+    }
 }
 
 /// Ensures the model directory exists.
@@ -122,25 +257,42 @@ pub fn ensure_model_dir() -> Result<PathBuf, AppError> {
 
 /// Checks if the Whisper model is available.
 ///
+/// `name` est le modèle configuré ([model] name), résolu par `resolve_model_path`.
 /// Returns Ok(path) if model exists, Err with instructions otherwise.
-pub fn check_model_availability() -> Result<PathBuf, AppError> {
-    let model_path = get_model_path()?;
-    if model_path.exists() {
-        Ok(model_path)
-    } else {
-        Err(AppError::ModelNotFound(format!(
-            "Modèle Whisper non trouvé.\n\n\
-             Pour installer le modèle:\n\
-             1. Exécutez: ./scripts/download-models.sh\n\
-             2. Ou téléchargez manuellement depuis:\n\
-                https://huggingface.co/ggerganov/whisper.cpp/tree/main\n\
-             3. Placez ggml-large-v3.bin dans:\n\
-                {}",
-            model_path.display()
-        )))
+pub fn check_model_availability(name: Option<&str>) -> Result<PathBuf, AppError> {
+    match resolve_model_path(name) {
+        Ok(path) if path.exists() => Ok(path),
+        Ok(_) | Err(AppError::ModelNotFound(_)) => Err(AppError::ModelNotFound(
+            model_not_found_message(name, &get_models_dir()?),
+        )),
+        Err(e) => Err(e),
     }
 }
 
+/// Instructions d'installation du modèle `name` (configuré ou demandé),
+/// ou de n'importe quel modèle sans nom configuré.
+fn model_not_found_message(name: Option<&str>, models_dir: &Path) -> String {
+    let (missing, to_install) = match name {
+        Some(name) => (name.to_string(), name.to_string()),
+        None => (
+            "aucun modèle installé".to_string(),
+            format!("{} (ou un autre modèle ggml)", DEFAULT_MODEL_NAME),
+        ),
+    };
+    format!(
+        "Modèle Whisper non trouvé: {}.\n\n\
+         Pour installer le modèle:\n\
+         1. Exécutez: ./scripts/download-models.sh\n\
+         2. Ou téléchargez manuellement depuis:\n\
+            https://huggingface.co/ggerganov/whisper.cpp/tree/main\n\
+         3. Placez {} dans:\n\
+            {}",
+        missing,
+        to_install,
+        models_dir.display()
+    )
+}
+
 /// Valide le format d'un fichier WAV avant transcription.
 ///
 /// # Arguments
@@ -148,7 +300,7 @@ pub fn check_model_availability() -> Result<PathBuf, AppError> {
 ///
 /// # Errors
 /// - `InvalidAudioFormat` si header WAV invalide, samples vides, ou format incorrect
-fn validate_wav_file(path: &Path) -> Result<(), AppError> {
+pub(crate) fn validate_wav_file(path: &Path) -> Result<(), AppError> {
     let reader = hound::WavReader::open(path).map_err(|e| {
         AppError::InvalidAudioFormat(format!("Fichier audio invalide ou corrompu: {}", e))
     })?;
@@ -182,19 +334,123 @@ fn validate_wav_file(path: &Path) -> Result<(), AppError> {
     Ok(())
 }
 
+/// Nombre de threads utilisés par défaut (même plafond que whisper.cpp)
+pub fn default_thread_count() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get().min(4))
+        .unwrap_or(1)
+}
+
+/// Vérifie qu'un code langue est connu de Whisper.
+///
+/// # Errors
+/// - `ConfigurationError` si la langue n'est pas supportée
+pub fn validate_language(code: &str) -> Result<(), AppError> {
+    if whisper_rs::get_lang_id(code).is_none() {
+        return Err(AppError::ConfigurationError(format!(
+            "Langue non supportée par Whisper: '{}'",
+            code
+        )));
+    }
+    Ok(())
+}
+
+/// Vérifie une requête avant sa mise en file, sans charger le modèle: une
+/// erreur remonte alors à l'appelant et l'enregistrement est gardé.
+///
+/// Le caractère multilingue est déduit du nom du fichier modèle
+/// (ggml-base.en.bin); `transcribe_samples` le revérifie sur le modèle chargé.
+///
+/// # Errors
+/// - `ConfigurationError` si la langue n'est pas supportée, si la traduction
+///   est demandée avec un modèle anglais uniquement, ou les tours de parole
+///   avec un modèle sans tinydiarize
+pub fn validate_options(options: &TranscriptionOptions, model_path: &Path) -> Result<(), AppError> {
+    if let Some(ref code) = options.language {
+        validate_language(code)?;
+    }
+    if options.translate && is_english_only(model_path) {
+        return Err(AppError::ConfigurationError(
+            "La traduction nécessite un modèle multilingue (pas un modèle .en)".to_string(),
+        ));
+    }
+    if options.diarize && !supports_diarization(model_path) {
+        return Err(diarization_unsupported(model_path));
+    }
+    Ok(())
+}
+
+/// Erreur des tours de parole demandés sans modèle tinydiarize.
+fn diarization_unsupported(model_path: &Path) -> AppError {
+    AppError::ConfigurationError(format!(
+        "La détection des locuteurs nécessite un modèle tinydiarize (ex: ggml-small.en-tdrz.bin), \
+         pas {}. Désactivez diarize ou changez de modèle.",
+        model_path.display()
+    ))
+}
+
+/// Indique si le nom du modèle est celui d'un modèle anglais uniquement
+/// (ggml-base.en.bin, ggml-small.en-tdrz.bin).
+fn is_english_only(model_path: &Path) -> bool {
+    model_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.contains(".en.") || name.contains(".en-"))
+}
+
+/// Détecte la langue parlée sur les 30 premières secondes de l'audio.
+///
+/// # Errors
+/// - `TranscriptionFailed` si le calcul du spectrogramme ou la détection échoue
+fn detect_language(
+    state: &mut whisper_rs::WhisperState,
+    samples: &[f32],
+    threads: usize,
+) -> Result<DetectedLanguage, AppError> {
+    state
+        .pcm_to_mel(samples, threads)
+        .map_err(|e| AppError::TranscriptionFailed(format!("Échec spectrogramme: {}", e)))?;
+
+    let (lang_id, probabilities) = state
+        .lang_detect(0, threads)
+        .map_err(|e| AppError::TranscriptionFailed(format!("Échec détection langue: {}", e)))?;
+
+    let code = whisper_rs::get_lang_str(lang_id).ok_or_else(|| {
+        AppError::TranscriptionFailed(format!("Langue détectée inconnue (id {})", lang_id))
+    })?;
+
+    Ok(DetectedLanguage {
+        code: code.to_string(),
+        probability: probabilities
+            .get(lang_id as usize)
+            .copied()
+            .unwrap_or(0.0),
+    })
+}
+
 /// Transcrit un fichier audio WAV en texte.
 ///
 /// # Arguments
 /// * `model` - WhisperModel chargé
 /// * `audio_path` - Chemin vers fichier WAV (16kHz mono requis)
+/// * `options` - Paramètres résolus (langue, traduction, glossaire, décodage)
+/// * `cancel` - Drapeau d'annulation vérifié par whisper.cpp pendant le décodage
 ///
 /// # Returns
-/// Texte transcrit ou AppError::TranscriptionFailed
+/// Transcript structuré: segments horodatés, texte complet (ou traduit en anglais)
+/// et langue détectée (si auto-détection)
 ///
 /// # Errors
 /// - `InvalidAudioFormat` si le fichier WAV est invalide (header, format, vide)
+/// - `ConfigurationError` si la langue demandée n'est pas supportée,
+///   ou si la traduction est demandée avec un modèle anglais uniquement
 /// - `TranscriptionFailed` si la transcription échoue
-pub fn transcribe_audio(model: &WhisperModel, audio_path: &Path) -> Result<String, AppError> {
+pub fn transcribe_audio(
+    model: &WhisperModel,
+    audio_path: &Path,
+    options: &TranscriptionOptions,
+    cancel: Option<&CancelFlag>,
+) -> Result<Transcript, AppError> {
     // 1. Valider le fichier audio AVANT de lire les samples
     validate_wav_file(audio_path)?;
 
@@ -213,47 +469,263 @@ pub fn transcribe_audio(model: &WhisperModel, audio_path: &Path) -> Result<Strin
         audio_path.display()
     );
 
+    transcribe_samples(model, &samples, options, cancel)
+}
+
+/// Transcrit des échantillons 16kHz mono déjà en mémoire.
+///
+/// # Errors
+/// - `ConfigurationError` si la langue demandée n'est pas supportée,
+///   ou si la traduction est demandée avec un modèle anglais uniquement
+/// - `TranscriptionFailed` si la transcription échoue ou est annulée
+pub fn transcribe_samples(
+    model: &WhisperModel,
+    samples: &[f32],
+    options: &TranscriptionOptions,
+    cancel: Option<&CancelFlag>,
+) -> Result<Transcript, AppError> {
+    if let Some(ref code) = options.language {
+        validate_language(code)?;
+    }
+
+    // Les modèles ".en" ne savent ni détecter la langue ni traduire
+    if options.translate && !model.context().is_multilingual() {
+        return Err(AppError::ConfigurationError(
+            "La traduction nécessite un modèle multilingue (pas un modèle .en)".to_string(),
+        ));
+    }
+
+    // tinydiarize n'existe que dans les modèles "tdrz" (tâches reprises, vérifiées avant)
+    let diarize = options.diarize;
+    if diarize && !supports_diarization(model.path()) {
+        return Err(diarization_unsupported(model.path()));
+    }
+
+    let threads = options.n_threads.unwrap_or_else(default_thread_count);
+
     // 3. Créer state pour transcription
     let mut state = model
         .context()
         .create_state()
         .map_err(|e| AppError::TranscriptionFailed(format!("Échec création state: {}", e)))?;
 
-    // 3. Configurer les paramètres
-    let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
-    params.set_language(Some("auto")); // Auto-détection de la langue
+    // 3. Langue: forcée par les options, sinon détectée (avec probabilité pour l'UI)
+    let detected_language = match options.language {
+        Some(_) => None,
+        None => Some(detect_language(&mut state, samples, threads)?),
+    };
+    let language = options
+        .language
+        .as_deref()
+        .or(detected_language.as_ref().map(|d| d.code.as_str()));
+
+    // 4. Glossaire: orthographe des noms propres et du jargon via le prompt initial
+    let initial_prompt = build_initial_prompt(
+        options.initial_prompt.as_deref(),
+        &options.vocabulary,
+        MAX_PROMPT_TOKENS,
+        |text| {
+            model
+                .context()
+                .tokenize(text, MAX_PROMPT_TOKENS * 4)
+                .map(|tokens| tokens.len())
+                .unwrap_or(usize::MAX)
+        },
+    );
+
+    // 5. Configurer les paramètres et exécuter la transcription
+    let decoding = &options.decoding;
+    let mut params = new_params(
+        sampling_strategy(decoding),
+        language,
+        initial_prompt.as_deref(),
+        options.translate,
+        threads,
+        cancel,
+    );
+    params.set_temperature(decoding.temperature);
+    params.set_temperature_inc(decoding.temperature_increment); // 0.0 = pas de repli
+    params.set_token_timestamps(true); // Horodatage par token pour les mots
+    params.set_tdrz_enable(diarize); // Tours de parole (modèles "tdrz" uniquement)
+
+    state
+        .full(params, samples)
+        .map_err(|e| decoding_error("Échec transcription", e, cancel))?;
+
+    // 6. Extraire les segments horodatés et leurs mots
+    let token_eot = model.context().token_eot();
+    let (mut segments, turns) = extract_segments(&state, token_eot);
+    if diarize {
+        assign_speakers(&mut segments, &turns, Speaker::A);
+    }
+    let num_segments = segments.len();
+
+    // 7. Lectures alternatives: whisper.cpp ne garde que la meilleure hypothèse,
+    // on relance donc des passes échantillonnées à température croissante
+    for pass in 0..decoding.alternatives {
+        let temperature =
+            (ALTERNATIVE_BASE_TEMPERATURE + pass as f32 * ALTERNATIVE_TEMPERATURE_STEP).min(1.0);
+        let mut alt_state = model
+            .context()
+            .create_state()
+            .map_err(|e| AppError::TranscriptionFailed(format!("Échec création state: {}", e)))?;
+        let mut alt_params = new_params(
+            SamplingStrategy::Greedy { best_of: 1 },
+            language,
+            initial_prompt.as_deref(),
+            options.translate,
+            threads,
+            cancel,
+        );
+        alt_params.set_temperature(temperature);
+        alt_params.set_temperature_inc(0.0);
+
+        alt_state
+            .full(alt_params, samples)
+            .map_err(|e| decoding_error("Échec passe alternative", e, cancel))?;
+        attach_alternatives(&mut segments, &extract_segments(&alt_state, token_eot).0);
+    }
+
+    // 8. Hallucinations: silences transcrits, boucles, génériques de sous-titres
+    let (segments, removed) = filter_segments(segments, &options.filter);
+
+    // Durée de l'audio: 16 échantillons par milliseconde à 16kHz
+    let duration_ms = (samples.len() / 16) as i64;
+    let mut transcript = Transcript::from_segments(segments, duration_ms);
+    transcript.removed = removed;
+    transcript.translated = options.translate;
+    transcript.source_language = language.map(str::to_string);
+    transcript.detected_language = detected_language;
+
+    println!(
+        "Transcription complete: {} segments, {} chars, language: {}, translated: {}",
+        num_segments,
+        transcript.text.len(),
+        transcript.source_language.as_deref().unwrap_or("?"),
+        transcript.translated
+    );
+
+    Ok(transcript)
+}
+
+/// Crée les paramètres communs à toutes les passes de décodage.
+fn new_params<'a>(
+    strategy: SamplingStrategy,
+    language: Option<&'a str>,
+    initial_prompt: Option<&str>,
+    translate: bool,
+    threads: usize,
+    cancel: Option<&CancelFlag>,
+) -> FullParams<'a, 'a> {
+    let mut params = FullParams::new(strategy);
+    params.set_language(language);
+    params.set_n_threads(threads as i32);
     params.set_print_progress(false);
     params.set_print_realtime(false);
     params.set_print_timestamps(false);
     params.set_single_segment(false);
-    params.set_translate(false); // Transcription uniquement, pas de traduction
+    params.set_translate(translate); // Tâche "translate": sortie en anglais
+    if let Some(prompt) = initial_prompt {
+        params.set_initial_prompt(prompt);
+    }
+    if let Some(cancel) = cancel {
+        let cancel = Arc::clone(cancel);
+        params.set_abort_callback_safe(move || cancel.load(Ordering::Relaxed));
+    }
+    params
+}
 
-    // 4. Exécuter transcription
-    state
-        .full(params, &samples)
-        .map_err(|e| AppError::TranscriptionFailed(format!("Échec transcription: {}", e)))?;
+/// Erreur de décodage, ou annulation si le drapeau a été levé.
+fn decoding_error(
+    context: &str,
+    error: impl std::fmt::Display,
+    cancel: Option<&CancelFlag>,
+) -> AppError {
+    if cancel.is_some_and(|c| c.load(Ordering::Relaxed)) {
+        AppError::TranscriptionFailed(TRANSCRIPTION_CANCELLED.to_string())
+    } else {
+        AppError::TranscriptionFailed(format!("{}: {}", context, error))
+    }
+}
 
-    // 5. Extraire le texte des segments
-    let num_segments = state.full_n_segments();
+/// Convertit la stratégie configurée en stratégie whisper-rs.
+fn sampling_strategy(decoding: &DecodingConfig) -> SamplingStrategy {
+    match decoding.strategy {
+        DecodingStrategy::Greedy => SamplingStrategy::Greedy {
+            best_of: decoding.best_of,
+        },
+        DecodingStrategy::BeamSearch => SamplingStrategy::BeamSearch {
+            beam_size: decoding.beam_size,
+            // -1.0: patience par défaut de whisper.cpp
+            patience: -1.0,
+        },
+    }
+}
+
+/// Indique si le modèle détecte les tours de parole (tinydiarize, ex: ggml-small.en-tdrz.bin).
+fn supports_diarization(model_path: &Path) -> bool {
+    model_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.contains("tdrz"))
+}
 
-    let mut text = String::new();
-    for i in 0..num_segments {
+/// Extrait les segments horodatés (et leurs mots) de l'état après `full()`,
+/// avec pour chacun l'indicateur tinydiarize de changement de locuteur après lui.
+fn extract_segments(
+    state: &whisper_rs::WhisperState,
+    token_eot: whisper_rs::WhisperTokenId,
+) -> (Vec<Segment>, Vec<bool>) {
+    let mut segments = Vec::new();
+    let mut turns = Vec::new();
+    for i in 0..state.full_n_segments() {
         if let Some(segment) = state.get_segment(i) {
             if let Ok(segment_text) = segment.to_str_lossy() {
-                text.push_str(&segment_text);
-                text.push(' ');
+                segments.push(Segment {
+                    start_ms: centiseconds_to_ms(segment.start_timestamp()),
+                    end_ms: centiseconds_to_ms(segment.end_timestamp()),
+                    text: segment_text.trim().to_string(),
+                    no_speech_probability: segment.no_speech_probability(),
+                    words: group_tokens_into_words(&extract_tokens(&segment, token_eot)),
+                    alternatives: Vec::new(),
+                    speaker: None,
+                    speaker_turn: false,
+                });
+                turns.push(segment.next_segment_speaker_turn());
             }
         }
     }
+    (segments, turns)
+}
 
-    let result = text.trim().to_string();
-    println!(
-        "Transcription complete: {} segments, {} chars",
-        num_segments,
-        result.len()
-    );
-
-    Ok(result)
+/// Extrait les tokens texte d'un segment avec leurs timestamps et probabilités.
+///
+/// Les tokens spéciaux (timestamps, [_BEG_], fin de texte...) ont un id
+/// supérieur ou égal à `token_eot` et sont ignorés.
+fn extract_tokens(
+    segment: &whisper_rs::WhisperSegment<'_>,
+    token_eot: whisper_rs::WhisperTokenId,
+) -> Vec<TokenTiming> {
+    let mut tokens = Vec::new();
+    for j in 0..segment.n_tokens() {
+        let Some(token) = segment.get_token(j) else {
+            continue;
+        };
+        if token.token_id() >= token_eot {
+            continue;
+        }
+        let Ok(bytes) = token.to_bytes() else {
+            continue;
+        };
+        let data = token.token_data();
+        tokens.push(TokenTiming {
+            bytes: bytes.to_vec(),
+            start_ms: centiseconds_to_ms(data.t0),
+            end_ms: centiseconds_to_ms(data.t1),
+            probability: data.p,
+        });
+    }
+    tokens
 }
 
 /// Lit un fichier WAV et retourne les samples f32 normalisés.
@@ -270,7 +742,7 @@ pub fn transcribe_audio(model: &WhisperModel, audio_path: &Path) -> Result<Strin
 /// # Note
 /// Cette fonction suppose que le fichier a été validé par `validate_wav_file()`.
 /// Ne pas appeler directement sans validation préalable.
-fn read_wav_samples(path: &Path) -> Result<Vec<f32>, AppError> {
+pub(crate) fn read_wav_samples(path: &Path) -> Result<Vec<f32>, AppError> {
     let reader = hound::WavReader::open(path).map_err(|e| {
         AppError::TranscriptionFailed(format!(
             "Impossible d'ouvrir le fichier WAV '{}': {}",
@@ -288,23 +760,63 @@ fn read_wav_samples(path: &Path) -> Result<Vec<f32>, AppError> {
         spec.sample_rate, spec.bits_per_sample, spec.channels
     );
 
-    // Convertir samples en f32 normalisés [-1.0, 1.0]
-    let samples: Vec<f32> = match spec.sample_format {
+    Ok(convert_samples(reader, usize::MAX))
+}
+
+/// Lit `len` échantillons à partir de l'échantillon `start`, sans charger le reste du fichier.
+///
+/// # Errors
+/// - `TranscriptionFailed` si le fichier est illisible ou `start` hors du fichier
+pub(crate) fn read_wav_range(path: &Path, start: usize, len: usize) -> Result<Vec<f32>, AppError> {
+    let mut reader = hound::WavReader::open(path).map_err(|e| {
+        AppError::TranscriptionFailed(format!(
+            "Impossible d'ouvrir le fichier WAV '{}': {}",
+            path.display(),
+            e
+        ))
+    })?;
+
+    reader.seek(start as u32).map_err(|e| {
+        AppError::TranscriptionFailed(format!("Position audio invalide ({}): {}", start, e))
+    })?;
+
+    Ok(convert_samples(reader, len))
+}
+
+/// Nombre d'échantillons du fichier WAV, lu depuis l'en-tête.
+///
+/// # Errors
+/// - `TranscriptionFailed` si le fichier est illisible
+pub(crate) fn wav_sample_count(path: &Path) -> Result<usize, AppError> {
+    let reader = hound::WavReader::open(path).map_err(|e| {
+        AppError::TranscriptionFailed(format!(
+            "Impossible d'ouvrir le fichier WAV '{}': {}",
+            path.display(),
+            e
+        ))
+    })?;
+    Ok(reader.duration() as usize)
+}
+
+/// Convertit jusqu'à `max` samples en f32 normalisés [-1.0, 1.0]
+fn convert_samples<R: std::io::Read>(reader: hound::WavReader<R>, max: usize) -> Vec<f32> {
+    let spec = reader.spec();
+    match spec.sample_format {
         hound::SampleFormat::Int => {
             let max_val = (1i64 << (spec.bits_per_sample - 1)) as f32;
             reader
                 .into_samples::<i32>()
+                .take(max)
                 .filter_map(|s| s.ok())
                 .map(|s| s as f32 / max_val)
                 .collect()
         }
         hound::SampleFormat::Float => reader
             .into_samples::<f32>()
+            .take(max)
             .filter_map(|s| s.ok())
             .collect(),
-    };
-
-    Ok(samples)
+    }
 }
 
 #[cfg(test)]
@@ -332,23 +844,9 @@ mod tests {
 
     #[test]
     fn test_model_not_found_error_message_quality() {
-        // Test error message quality by checking a non-existent path directly
-        // This ensures the test always validates error content, regardless of model presence
-        let fake_path = PathBuf::from("/nonexistent/test/path/model.bin");
-
-        // Simulate the error that check_model_availability would produce
-        let error = AppError::ModelNotFound(format!(
-            "Modèle Whisper non trouvé.\n\n\
-             Pour installer le modèle:\n\
-             1. Exécutez: ./scripts/download-models.sh\n\
-             2. Ou téléchargez manuellement depuis:\n\
-                https://huggingface.co/ggerganov/whisper.cpp/tree/main\n\
-             3. Placez ggml-large-v3.bin dans:\n\
-                {}",
-            fake_path.display()
-        ));
+        let models_dir = PathBuf::from("/nonexistent/test/models");
 
-        let msg = error.to_string();
+        let msg = model_not_found_message(Some("ggml-medium.bin"), &models_dir);
         assert!(
             msg.contains("download-models.sh"),
             "Error should mention download script"
@@ -358,23 +856,38 @@ mod tests {
             "Error should mention download source"
         );
         assert!(
-            msg.contains("ggml-large-v3.bin"),
-            "Error should mention model filename"
+            msg.contains("Placez ggml-medium.bin dans"),
+            "Error should name the configured model"
+        );
+        assert!(msg.contains("/nonexistent/test/models"));
+
+        let msg = model_not_found_message(None, &models_dir);
+        assert!(
+            msg.contains("ggml-large-v3.bin (ou un autre modèle ggml)"),
+            "Without a configured model, any installed model will do"
         );
     }
 
+    #[test]
+    fn test_check_model_availability_names_missing_configured_model() {
+        match check_model_availability(Some("ggml-absent-test.bin")) {
+            Err(AppError::ModelNotFound(msg)) => assert!(msg.contains("ggml-absent-test.bin")),
+            other => panic!("Expected ModelNotFound, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_check_model_availability_returns_error_for_missing_model() {
         // This test verifies the function behavior
         // It will return Ok if model exists (valid), Err if not (also valid)
-        let result = check_model_availability();
+        let result = check_model_availability(None);
 
         match result {
             Ok(path) => {
-                // Model exists - verify path is correct
+                // A model exists (large-v3 or another installed one) - verify location
                 assert!(
-                    path.to_string_lossy().contains("ggml-large-v3.bin"),
-                    "Should return correct model path"
+                    path.starts_with(get_models_dir().unwrap()),
+                    "Should return a path in the models dir"
                 );
             }
             Err(AppError::ModelNotFound(msg)) => {
@@ -390,6 +903,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_validate_language() {
+        assert!(validate_language("fr").is_ok());
+        assert!(validate_language("en").is_ok());
+        match validate_language("xx") {
+            Err(AppError::ConfigurationError(msg)) => assert!(msg.contains("xx")),
+            other => panic!("Expected ConfigurationError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_options_before_queueing() {
+        let options = |language: Option<&str>, translate: bool| TranscriptionOptions {
+            language: language.map(str::to_string),
+            translate,
+            ..TranscriptionOptions::resolve(
+                &crate::config::AppConfig::default(),
+                &crate::transcription::options::UserFiles::default(),
+                &Default::default(),
+            )
+            .unwrap()
+        };
+        let multilingual = Path::new("/models/ggml-medium.bin");
+        let english = Path::new("/models/ggml-base.en.bin");
+
+        assert!(validate_options(&options(Some("fr"), true), multilingual).is_ok());
+        assert!(validate_options(&options(None, false), english).is_ok());
+        assert!(matches!(
+            validate_options(&options(Some("xx"), false), multilingual),
+            Err(AppError::ConfigurationError(_))
+        ));
+        assert!(matches!(
+            validate_options(&options(None, true), english),
+            Err(AppError::ConfigurationError(_))
+        ));
+        assert!(is_english_only(Path::new("/models/ggml-small.en-tdrz.bin")));
+
+        let diarize = TranscriptionOptions {
+            diarize: true,
+            ..options(None, false)
+        };
+        assert!(validate_options(&diarize, Path::new("/models/ggml-small.en-tdrz.bin")).is_ok());
+        assert!(matches!(
+            validate_options(&diarize, multilingual),
+            Err(AppError::ConfigurationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_supports_diarization_only_for_tdrz_models() {
+        assert!(supports_diarization(Path::new("/models/ggml-small.en-tdrz.bin")));
+        assert!(!supports_diarization(Path::new("/models/ggml-large-v3.bin")));
+        assert!(!supports_diarization(Path::new("/models/tdrz/ggml-base.bin")));
+    }
+
     #[test]
     fn test_whisper_state_default() {
         let state = WhisperState::default();
@@ -398,6 +966,22 @@ mod tests {
         assert!(guard.is_none(), "Model should be None by default");
     }
 
+    #[test]
+    fn test_unload_if_idle_without_model() {
+        let state = WhisperState::default();
+        assert!(!state.unload_if_idle(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_unload_if_idle_skips_locked_model() {
+        let state = WhisperState::default();
+        let _guard = state.model.try_lock().unwrap();
+        assert!(
+            !state.unload_if_idle(Duration::ZERO),
+            "Locked model is in use and must not be unloaded"
+        );
+    }
+
     #[test]
     fn test_ensure_model_dir_creates_path() {
         // This test verifies the function doesn't panic
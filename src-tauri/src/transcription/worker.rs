@@ -0,0 +1,560 @@
+//! Worker module - runs one transcription job end to end
+//!
+//! Drives a `TranscriptionEngine` for a queued job, post-processes the text,
+//! reports progress through `JobEvents` and always cleans up after itself:
+//! audio (and checkpoint) are deleted on success, error and cancellation
+//! (NFR-SEC-3). The exception is a successful job handed over to the
+//! retention window for re-transcription. Only a job interrupted without the
+//! user asking (crash, shutdown) keeps its checkpoint and resumes at startup.
+//! A recording recognized as a voice editing command is matched on Whisper's
+//! raw text, skips post-processing and is never retained.
+
+use std::path::Path;
+
+use crate::error::AppError;
+use crate::note::{self, edit::ParsedCommand};
+use crate::text;
+use crate::transcription::chunking::{self, ChunkResult};
+use crate::transcription::engine::{EngineEvent, TranscriptionEngine};
+use crate::transcription::queue::{Job, JobId};
+use crate::transcription::transcript::Transcript;
+
+/// Destination des événements d'une tâche (événements Tauri en production).
+pub trait JobEvents: Send + Sync {
+    /// Progression de la tâche (0-100)
+    fn progress(&self, job_id: JobId, percent: i32);
+    /// Fenêtre terminée d'un long enregistrement
+    fn chunk(&self, job_id: JobId, chunk: &ChunkResult);
+    /// Transcription terminée; l'audio est déjà supprimé ou conservé
+    fn complete(&self, job_id: JobId, transcript: Transcript, audio_deleted: bool);
+    /// Enregistrement reconnu comme commande d'édition de la note; l'audio
+    /// est déjà supprimé (une commande n'est jamais re-transcrite)
+    fn edit(&self, job_id: JobId, command: ParsedCommand, audio_deleted: bool);
+    /// Tâche annulée, avec l'information de cleanup
+    fn cancelled(&self, job_id: JobId, audio_deleted: bool);
+    /// Échec de la tâche, avec l'information de cleanup
+    fn failed(&self, job_id: JobId, error: &AppError, audio_deleted: bool);
+    /// Tâche réussie: prend en charge son audio pour une re-transcription.
+    /// Retourne false si l'audio doit être supprimé (rétention désactivée).
+    fn keep_audio(&self, job: &Job) -> bool;
+}
+
+/// Supprime le fichier audio temporaire d'une tâche.
+/// Retourne true si le fichier a été supprimé.
+pub fn cleanup_audio(path: &Path) -> bool {
+    // NFR-SEC-1, NFR-SEC-3: Cleanup immédiat du fichier audio temporaire (privacy-first)
+    if let Err(e) = std::fs::remove_file(path) {
+        eprintln!("Warning: Failed to cleanup temp audio file: {:?}", e);
+        false
+    } else {
+        println!("Temp audio file cleaned up: {}", path.display());
+        true
+    }
+}
+
+/// Supprime l'audio d'une tâche et son éventuel checkpoint (y compris un
+/// checkpoint en cours d'écriture). Retourne true si le fichier audio a été supprimé.
+pub fn discard_job_files(path: &Path) -> bool {
+    chunking::remove_checkpoint(path);
+    cleanup_audio(path)
+}
+
+/// Supprime les fichiers d'une tâche; un enregistrement en mémoire n'en a
+/// aucun (il disparaît avec la tâche). Retourne true si plus rien ne reste.
+pub fn discard_audio(job: &Job) -> bool {
+    if job.samples.is_some() {
+        return true;
+    }
+    discard_job_files(&job.audio_path)
+}
+
+/// Exécute une tâche: transcription, événements, cleanup.
+///
+/// Bloquant: à appeler hors du runtime async (`spawn_blocking`).
+pub fn run_job(engine: &dyn TranscriptionEngine, events: &dyn JobEvents, job: &Job) {
+    let job_id = job.id;
+
+    // Émettre progression initiale
+    events.progress(job_id, 0);
+
+    // Note: whisper-rs ne supporte pas les callbacks de progression natifs
+    // On simule avec des étapes discrètes (par fenêtre pour les longs enregistrements)
+    let result = engine.transcribe(job, &mut |event| match event {
+        EngineEvent::ModelLoading => events.progress(job_id, 5),
+        EngineEvent::Started => {
+            events.progress(job_id, 10);
+            events.progress(job_id, 20);
+        }
+        EngineEvent::Chunk(chunk) => {
+            let done = (chunk.index + 1) * 79 / chunk.total.max(1);
+            events.progress(job_id, 20 + done as i32);
+            events.chunk(job_id, chunk);
+        }
+    });
+
+    match result {
+        Ok(mut transcript) if !job.is_cancelled() => {
+            // Sur le texte de Whisper: le post-traitement (dictée, règles)
+            // pourrait changer les mots de la commande
+            let command = if job.options.voice_commands {
+                note::parse_command(&transcript.raw_text)
+            } else {
+                None
+            };
+            if let Some(mut command) = command {
+                // Seule la dictée qui suit la commande est post-traitée
+                command.rest = text::process_excerpt(
+                    &command.rest,
+                    &transcript,
+                    &job.options.postprocess,
+                    job.options.rules.compiled(),
+                );
+                events.progress(job_id, 100);
+                let audio_deleted = discard_audio(job);
+                events.edit(job_id, command, audio_deleted);
+                return;
+            }
+
+            text::process_transcript(
+                &mut transcript,
+                &job.options.postprocess,
+                job.options.rules.compiled(),
+            );
+            events.progress(job_id, 100);
+            // Rétention opt-in: l'audio reste disponible pour une re-transcription.
+            // Décidée avant l'événement final, qui indique si l'audio est supprimé.
+            let audio_deleted = !events.keep_audio(job) && discard_audio(job);
+            events.complete(job_id, transcript, audio_deleted);
+            return;
+        }
+        Err(e) if !job.is_cancelled() => {
+            eprintln!("Transcription failed: {:?}", e);
+            let deleted = discard_audio(job);
+            events.failed(job_id, &e, deleted);
+            return; // Ne pas faire double cleanup
+        }
+        // Annulation explicite: rien n'est gardé, même une transcription découpée
+        _ => {}
+    }
+
+    // Cleanup TOUJOURS exécuté après une annulation
+    let audio_deleted = discard_audio(job);
+
+    if job.is_cancelled() {
+        println!("Transcription job {} cancelled", job_id);
+        events.cancelled(job_id, audio_deleted);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::atomic::Ordering;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use crate::config::AppConfig;
+    use crate::note::EditCommand;
+    use crate::text::rules::{ReplacementRule, RuleSet};
+    use crate::transcription::mock::MockEngine;
+    use crate::transcription::options::{TranscriptionOptions, TranscriptionRequest, UserFiles};
+    use crate::transcription::queue::JobQueue;
+    use crate::transcription::whisper::TRANSCRIPTION_CANCELLED;
+
+    /// Événement enregistré par `RecordedEvents`.
+    #[derive(Debug, Clone, PartialEq)]
+    enum Event {
+        Progress(i32),
+        Chunk(usize),
+        Complete {
+            text: String,
+            audio_deleted: bool,
+        },
+        Edit {
+            command: EditCommand,
+            rest: String,
+            audio_deleted: bool,
+        },
+        Cancelled {
+            audio_deleted: bool,
+        },
+        Failed {
+            message: String,
+            audio_deleted: bool,
+        },
+    }
+
+    #[derive(Default)]
+    struct RecordedEvents {
+        events: Mutex<Vec<(JobId, Event)>>,
+        /// Simule une fenêtre de rétention active
+        keep_audio: bool,
+    }
+
+    impl RecordedEvents {
+        fn push(&self, job_id: JobId, event: Event) {
+            self.events.lock().unwrap().push((job_id, event));
+        }
+
+        fn of(&self, job_id: JobId) -> Vec<Event> {
+            self.events
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(id, _)| *id == job_id)
+                .map(|(_, e)| e.clone())
+                .collect()
+        }
+
+        fn last(&self, job_id: JobId) -> Event {
+            self.of(job_id).pop().expect("at least one event")
+        }
+    }
+
+    impl JobEvents for RecordedEvents {
+        fn progress(&self, job_id: JobId, percent: i32) {
+            self.push(job_id, Event::Progress(percent));
+        }
+        fn chunk(&self, job_id: JobId, chunk: &ChunkResult) {
+            self.push(job_id, Event::Chunk(chunk.index));
+        }
+        fn complete(&self, job_id: JobId, transcript: Transcript, audio_deleted: bool) {
+            self.push(
+                job_id,
+                Event::Complete {
+                    text: transcript.text,
+                    audio_deleted,
+                },
+            );
+        }
+        fn edit(&self, job_id: JobId, command: ParsedCommand, audio_deleted: bool) {
+            self.push(
+                job_id,
+                Event::Edit {
+                    command: command.command,
+                    rest: command.rest,
+                    audio_deleted,
+                },
+            );
+        }
+        fn cancelled(&self, job_id: JobId, audio_deleted: bool) {
+            self.push(job_id, Event::Cancelled { audio_deleted });
+        }
+        fn failed(&self, job_id: JobId, error: &AppError, audio_deleted: bool) {
+            self.push(
+                job_id,
+                Event::Failed {
+                    message: error.to_string(),
+                    audio_deleted,
+                },
+            );
+        }
+        fn keep_audio(&self, _job: &Job) -> bool {
+            self.keep_audio
+        }
+    }
+
+    /// Transcription terminée dont l'audio a été supprimé.
+    fn completed(text: &str) -> Event {
+        Event::Complete {
+            text: text.to_string(),
+            audio_deleted: true,
+        }
+    }
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir()
+            .join("vocal-note-taker-tests")
+            .join(format!("worker_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn options() -> TranscriptionOptions {
+        TranscriptionOptions::resolve(
+            &AppConfig::default(),
+            &UserFiles::default(),
+            &TranscriptionRequest::default(),
+        )
+        .unwrap()
+    }
+
+    /// Crée un enregistrement factice et la tâche correspondante.
+    fn job_for(dir: &Path, name: &str) -> Job {
+        job_with(dir, name, options())
+    }
+
+    fn job_with(dir: &Path, name: &str, options: TranscriptionOptions) -> Job {
+        let audio = dir.join(name);
+        fs::write(&audio, b"RIFF").unwrap();
+        let mut queue = JobQueue::default();
+        queue.enqueue(audio, options).unwrap();
+        queue.start_next().unwrap()
+    }
+
+    #[test]
+    fn test_success_emits_transcript_and_deletes_audio() {
+        let dir = test_dir("success");
+        let job = job_for(&dir, "recording.wav");
+        let engine = MockEngine::new().then_text("Bonjour à tous.");
+        let events = RecordedEvents::default();
+
+        run_job(&engine, &events, &job);
+
+        assert_eq!(
+            events.of(job.id),
+            vec![
+                Event::Progress(0),
+                Event::Progress(5),
+                Event::Progress(10),
+                Event::Progress(20),
+                Event::Progress(100),
+                completed("Bonjour à tous."),
+            ]
+        );
+        assert!(!job.audio_path.exists(), "Audio deleted after success");
+        assert_eq!(engine.calls(), vec![job.audio_path.clone()]);
+    }
+
+    #[test]
+    fn test_retained_audio_is_kept_after_success() {
+        let dir = test_dir("retained");
+        let job = job_for(&dir, "recording.wav");
+        let engine = MockEngine::new().then_text("Bonjour.");
+        let events = RecordedEvents {
+            keep_audio: true,
+            ..Default::default()
+        };
+
+        run_job(&engine, &events, &job);
+
+        assert_eq!(
+            events.last(job.id),
+            Event::Complete {
+                text: "Bonjour.".to_string(),
+                audio_deleted: false,
+            }
+        );
+        assert!(job.audio_path.exists(), "Audio kept for re-transcription");
+    }
+
+    #[test]
+    fn test_voice_command_is_matched_before_post_processing_and_never_retained() {
+        let dir = test_dir("voice_command");
+        let mut options = options();
+        options.voice_commands = true;
+        options.postprocess.enabled = true;
+        options.postprocess.apply_rules = true;
+        // Une règle qui casserait la commande si elle était appliquée avant
+        options.rules = RuleSet::new(vec![ReplacementRule {
+            find: "phrase".to_string(),
+            replace: "sentence".to_string(),
+            regex: false,
+            whole_word: true,
+            preserve_case: true,
+        }])
+        .unwrap();
+        let job = job_with(&dir, "recording.wav", options);
+        let engine =
+            MockEngine::new().then_text("Supprime la dernière phrase. Cette phrase reste.");
+        let events = RecordedEvents {
+            keep_audio: true,
+            ..Default::default()
+        };
+
+        run_job(&engine, &events, &job);
+
+        assert_eq!(
+            events.last(job.id),
+            Event::Edit {
+                command: EditCommand::DeleteLastSentence,
+                rest: "Cette sentence reste.".to_string(),
+                audio_deleted: true,
+            }
+        );
+        assert!(
+            !job.audio_path.exists(),
+            "A recording consumed as an edit is not retained"
+        );
+    }
+
+    #[test]
+    fn test_engine_error_deletes_audio_and_reports_it() {
+        let dir = test_dir("error");
+        let job = job_for(&dir, "recording.wav");
+        let engine =
+            MockEngine::new().then_error(AppError::ModelLoadFailed("Mémoire insuffisante".into()));
+        let events = RecordedEvents::default();
+
+        run_job(&engine, &events, &job);
+
+        match events.last(job.id) {
+            Event::Failed {
+                message,
+                audio_deleted,
+            } => {
+                assert!(message.contains("Mémoire insuffisante"));
+                assert!(audio_deleted);
+            }
+            other => panic!("Expected Failed, got {:?}", other),
+        }
+        assert!(
+            !job.audio_path.exists(),
+            "NFR-SEC-3: audio deleted on error"
+        );
+    }
+
+    #[test]
+    fn test_error_leaves_no_checkpoint() {
+        let dir = test_dir("error_checkpoint");
+        let job = job_for(&dir, "recording.wav");
+        let checkpoint = chunking::checkpoint_path(&job.audio_path);
+        fs::write(&checkpoint, b"{}").unwrap();
+        let engine = MockEngine::new().then_error(AppError::TranscriptionFailed("x".into()));
+
+        run_job(&engine, &RecordedEvents::default(), &job);
+
+        assert!(!job.audio_path.exists());
+        assert!(!checkpoint.exists());
+    }
+
+    #[test]
+    fn test_in_memory_job_has_nothing_to_delete() {
+        let mut queue = JobQueue::default();
+        queue
+            .enqueue_samples(
+                PathBuf::from("memory://recording-1-0"),
+                vec![0.0; 16].into(),
+                options(),
+            )
+            .unwrap();
+        let job = queue.start_next().unwrap();
+        let engine = MockEngine::new().then_error(AppError::TranscriptionFailed("boom".into()));
+        let events = RecordedEvents::default();
+
+        run_job(&engine, &events, &job);
+
+        assert_eq!(
+            engine.calls(),
+            vec![PathBuf::from("memory://recording-1-0")]
+        );
+        assert!(matches!(
+            events.last(job.id),
+            Event::Failed {
+                audio_deleted: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_cancel_during_transcription_deletes_audio() {
+        let dir = test_dir("cancel");
+        let job = job_for(&dir, "recording.wav");
+        let engine = MockEngine::new().then_delay(Duration::from_secs(10), "Trop tard.");
+        let events = RecordedEvents::default();
+
+        let cancel = Arc::clone(&job.cancel);
+        let canceller = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            cancel.store(true, Ordering::Relaxed);
+        });
+        run_job(&engine, &events, &job);
+        canceller.join().unwrap();
+
+        assert_eq!(
+            events.last(job.id),
+            Event::Cancelled {
+                audio_deleted: true
+            }
+        );
+        assert!(!events
+            .of(job.id)
+            .iter()
+            .any(|e| matches!(e, Event::Complete { .. } | Event::Failed { .. })));
+        assert!(!job.audio_path.exists());
+    }
+
+    #[test]
+    fn test_cancelled_chunked_job_deletes_checkpoint() {
+        let dir = test_dir("cancel_checkpoint");
+        let job = job_for(&dir, "recording.wav");
+        let checkpoint = chunking::checkpoint_path(&job.audio_path);
+        let checkpoint_tmp = chunking::checkpoint_tmp_path(&job.audio_path);
+        fs::write(&checkpoint, b"{}").unwrap();
+        fs::write(&checkpoint_tmp, b"{").unwrap();
+        job.cancel.store(true, Ordering::Relaxed);
+        let engine = MockEngine::new().then_error(AppError::TranscriptionFailed(
+            TRANSCRIPTION_CANCELLED.into(),
+        ));
+        let events = RecordedEvents::default();
+
+        run_job(&engine, &events, &job);
+
+        assert_eq!(
+            events.last(job.id),
+            Event::Cancelled {
+                audio_deleted: true
+            }
+        );
+        assert!(!job.audio_path.exists(), "NFR-SEC-3: cancel deletes audio");
+        assert!(!checkpoint.exists());
+        assert!(!checkpoint_tmp.exists());
+    }
+
+    #[test]
+    fn test_chunks_report_progress() {
+        let dir = test_dir("chunks");
+        let job = job_for(&dir, "recording.wav");
+        let engine = MockEngine::new().then_chunks(&["Première partie.", "Seconde partie."]);
+        let events = RecordedEvents::default();
+
+        run_job(&engine, &events, &job);
+
+        let recorded = events.of(job.id);
+        assert!(recorded.contains(&Event::Chunk(0)));
+        assert!(recorded.contains(&Event::Chunk(1)));
+        assert!(recorded.contains(&Event::Progress(59)));
+        assert_eq!(
+            events.last(job.id),
+            completed("Première partie. Seconde partie.")
+        );
+    }
+
+    #[test]
+    fn test_jobs_run_in_order_with_scripted_results() {
+        let dir = test_dir("queue");
+        let mut queue = JobQueue::default();
+        for name in ["a.wav", "b.wav", "c.wav"] {
+            let audio = dir.join(name);
+            fs::write(&audio, b"RIFF").unwrap();
+            queue.enqueue(audio, options()).unwrap();
+        }
+        let engine = MockEngine::new()
+            .then_text("Premier.")
+            .then_error(AppError::TranscriptionFailed("Échec".into()))
+            .then_text("Troisième.");
+        let events = RecordedEvents::default();
+
+        while let Some(job) = queue.start_next() {
+            run_job(&engine, &events, &job);
+            queue.finish(job.id);
+        }
+
+        assert_eq!(events.last(1), completed("Premier."));
+        assert!(matches!(events.last(2), Event::Failed { .. }));
+        assert_eq!(events.last(3), completed("Troisième."));
+        assert_eq!(
+            engine.calls(),
+            vec![dir.join("a.wav"), dir.join("b.wav"), dir.join("c.wav")]
+        );
+        assert_eq!(
+            fs::read_dir(&dir).unwrap().count(),
+            0,
+            "No audio left behind"
+        );
+    }
+}
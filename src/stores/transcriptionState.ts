@@ -3,9 +3,10 @@
  * Manages transcription progress and results.
  *
  * @listens transcription-progress - Updates progress (0-100)
- * @listens transcription-complete - Stores transcribed text
+ * @listens transcription-complete - Stores transcribed text, detected language and filtered segments
  */
 import { writable } from 'svelte/store';
+import type { DetectedLanguage, RemovedSegment } from '../types';
 
 /**
  * Transcription progress store (0-100).
@@ -31,6 +32,53 @@ export const transcriptionText = {
   reset: () => textStore.set(''),
 };
 
+/**
+ * Detected language store.
+ * Null when the language was forced (config, profile or request).
+ */
+const detectedLanguageStore = writable<DetectedLanguage | null>(null);
+
+export const detectedLanguage = {
+  subscribe: detectedLanguageStore.subscribe,
+  set: (value: DetectedLanguage | null) => detectedLanguageStore.set(value),
+  reset: () => detectedLanguageStore.set(null),
+};
+
+/**
+ * Source language store when the text is an English translation.
+ * Null when the text was transcribed as spoken.
+ */
+const translatedFromStore = writable<string | null>(null);
+
+export const translatedFrom = {
+  subscribe: translatedFromStore.subscribe,
+  set: (value: string | null) => translatedFromStore.set(value),
+  reset: () => translatedFromStore.set(null),
+};
+
+/**
+ * Segments removed by the hallucination filter ([transcription.filter]).
+ */
+const removedSegmentsStore = writable<RemovedSegment[]>([]);
+
+export const removedSegments = {
+  subscribe: removedSegmentsStore.subscribe,
+  set: (value: RemovedSegment[]) => removedSegmentsStore.set(value),
+  reset: () => removedSegmentsStore.set([]),
+};
+
+/**
+ * True when speaker turns were detected but their labels were dropped
+ * (the post-processed text no longer has one line per turn).
+ */
+const speakerLabelsDroppedStore = writable<boolean>(false);
+
+export const speakerLabelsDropped = {
+  subscribe: speakerLabelsDroppedStore.subscribe,
+  set: (value: boolean) => speakerLabelsDroppedStore.set(value),
+  reset: () => speakerLabelsDroppedStore.set(false),
+};
+
 /**
  * Reset all transcription state.
  * Call before starting a new transcription.
@@ -38,4 +86,8 @@ export const transcriptionText = {
 export function resetTranscription(): void {
   transcriptionProgress.reset();
   transcriptionText.reset();
+  detectedLanguage.reset();
+  translatedFrom.reset();
+  removedSegments.reset();
+  speakerLabelsDropped.reset();
 }
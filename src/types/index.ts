@@ -15,7 +15,9 @@ export type AppErrorType =
   | 'HotkeyRegistrationFailed'
   | 'ModelNotFound'
   | 'ModelLoadFailed'
-  | 'InvalidAudioFormat';
+  | 'InvalidAudioFormat'
+  | 'ModelImportFailed'
+  | 'NoteEditFailed';
 
 /**
  * Application error structure received from backend via IPC.
@@ -29,6 +31,8 @@ export interface AppError {
    * Present when error occurs during transcription process.
    */
   audio_deleted?: boolean;
+  /** Transcription job the error belongs to; null for errors outside a job (model preload). */
+  job_id?: number | null;
 }
 
 /**
@@ -36,7 +40,259 @@ export interface AppError {
  */
 export type RecordingState = 'idle' | 'recording' | 'transcribing';
 
+/**
+ * Installed Whisper model, returned by the import_model command
+ * and emitted with the model-imported event.
+ */
+export interface ModelInfo {
+  name: string;
+  path: string;
+  size_bytes: number;
+}
+
+/**
+ * Payload of model-import-progress events.
+ */
+export interface ModelImportProgress {
+  percent: number;
+  bytes_copied: number;
+  total_bytes: number;
+}
+
+/**
+ * Payload of model-loading events (background preload, [model] preload = true).
+ */
+export interface ModelLoading {
+  path: string;
+}
+
+/**
+ * Payload of model-ready events. load_ms is 0 when the model was already loaded.
+ */
+export interface ModelReady {
+  load_ms: number;
+}
+
+/**
+ * Payload of model-unloaded events (model freed after [model] idle_timeout_secs).
+ */
+export interface ModelUnloaded {
+  idle_timeout_secs: number;
+}
+
+/**
+ * One benchmark_model measurement (model × thread count).
+ * real_time_factor = decode_ms / audio_ms; below 1.0 is faster than real time.
+ */
+export interface BenchmarkResult {
+  model: string;
+  n_threads: number;
+  load_ms: number;
+  decode_ms: number;
+  audio_ms: number;
+  real_time_factor: number;
+  /** Set when the model failed to load or transcribe (timings are then 0). */
+  error: string | null;
+}
+
+/**
+ * Payload of benchmark-progress events (index is 1-based).
+ */
+export interface BenchmarkProgress {
+  index: number;
+  total: number;
+  result: BenchmarkResult;
+}
+
+/**
+ * Language auto-detected by Whisper (ISO 639-1 code + probability 0-1).
+ */
+export interface DetectedLanguage {
+  code: string;
+  probability: number;
+}
+
+/**
+ * Timed word with its confidence (mean token probability, 0-1).
+ */
+export interface Word {
+  text: string;
+  start_ms: number;
+  end_ms: number;
+  probability: number;
+}
+
+/**
+ * Timed transcript segment (milliseconds from the start of the audio).
+ */
+export interface Segment {
+  start_ms: number;
+  end_ms: number;
+  text: string;
+  no_speech_probability: number;
+  words: Word[];
+  /** Lectures alternatives issues des passes supplémentaires ([transcription.decoding] alternatives) */
+  alternatives: string[];
+  /** Locuteur de l'entretien, null sans détection des tours (diarize) */
+  speaker: 'A' | 'B' | null;
+  /** Le locuteur change après ce segment */
+  speaker_turn: boolean;
+}
+
+/**
+ * Segment dropped by the hallucination filter, with the reason.
+ */
+export interface RemovedSegment {
+  start_ms: number;
+  end_ms: number;
+  text: string;
+  reason: 'no_speech' | 'repetition' | 'phantom_phrase';
+}
+
+/**
+ * Structured transcription result.
+ * detected_language is null when the language was forced by config, profile or request.
+ * translated is true when text is an English translation of source_language speech.
+ * With speaker turn detection, text has one line per turn and segments carry
+ * the speaker; the note renders each turn as "Speaker A: ...".
+ */
+export interface Transcript {
+  /** Post-processed text ([postprocess]: fillers, duplicates, capitalization) */
+  text: string;
+  /** Whisper's text before post-processing */
+  raw_text: string;
+  segments: Segment[];
+  duration_ms: number;
+  detected_language: DetectedLanguage | null;
+  source_language: string | null;
+  translated: boolean;
+  /** Segments removed by the hallucination filter ([transcription.filter]) */
+  removed: RemovedSegment[];
+}
+
+/**
+ * Payload of transcription-complete events (flattened Transcript + job ID).
+ * `note` is the current note after this transcript: in append mode
+ * ([note] append) it holds every take since the last new note.
+ * `speaker_labels_dropped` is true when speaker turns were detected but no
+ * longer matched the post-processed lines, so the note has no speaker labels.
+ */
+export type TranscriptionPayload = Transcript & {
+  job_id: number;
+  note: NoteSnapshot;
+  speaker_labels_dropped: boolean;
+};
+
+/**
+ * Payload of transcription-progress events.
+ */
+export interface TranscriptionProgress {
+  job_id: number;
+  percent: number;
+}
+
+/**
+ * Active transcription job returned by list_jobs (running first, then queued).
+ */
+export interface JobInfo {
+  id: number;
+  status: 'queued' | 'running';
+  position: number;
+  audio_path: string;
+}
+
+/**
+ * Payload of job-queued events (position 0 = starts immediately).
+ */
+export interface JobQueued {
+  job_id: number;
+  position: number;
+}
+
+/**
+ * Payload of job-cancelled events.
+ */
+export interface JobCancelled {
+  job_id: number;
+  audio_deleted: boolean;
+}
+
+/**
+ * Payload of recording-retained events ([privacy] retention_secs): the audio
+ * of this job can be re-transcribed until the window expires.
+ */
+export interface RecordingRetained {
+  job_id: number;
+  expires_in_secs: number;
+}
+
+/**
+ * Payload of recording-discarded events (retention window expired or replaced).
+ */
+export interface RecordingDiscarded {
+  job_id: number;
+  audio_deleted: boolean;
+}
+
+/**
+ * Payload of transcription-chunk events, emitted after each window of a long
+ * recording ([transcription.chunking]); index is 0-based.
+ */
+export interface TranscriptionChunk {
+  job_id: number;
+  index: number;
+  total: number;
+  start_ms: number;
+  end_ms: number;
+  text: string;
+  segments: Segment[];
+}
+
+/**
+ * User-defined replacement rule ([postprocess] rules_file, rules.toml).
+ */
+export interface ReplacementRule {
+  find: string;
+  replace: string;
+  regex: boolean;
+  whole_word: boolean;
+  preserve_case: boolean;
+}
+
+/**
+ * Result of preview_rules: the sample text after the rules, and how many
+ * replacements each rule made (same order as the rules).
+ */
+export interface RulesPreview {
+  text: string;
+  matches: number[];
+}
+
+/**
+ * Current note kept by the backend (get_note, undo_note, new_note and
+ * note-cleared events).
+ */
+export interface NoteSnapshot {
+  text: string;
+  can_undo: boolean;
+}
+
+/**
+ * Voice editing command recognized at the start of a recording.
+ */
+export type EditCommand =
+  | { type: 'delete_last_sentence' }
+  | { type: 'delete_last_word' }
+  | { type: 'replace'; find: string; replace: string }
+  | { type: 'undo' }
+  | { type: 'new_note' };
+
+/**
+ * Payload of note-edited events: the recording edited the current note
+ * instead of replacing it.
+ */
+export type NoteEdited = NoteSnapshot & { job_id: number; command: EditCommand };
+
 // Placeholder for future type definitions:
 // - AppConfig
 // - WaveformData
-// - TranscriptionResult